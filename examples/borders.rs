@@ -15,17 +15,16 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::Line,
-    widgets::{Block, BorderType, Borders, Paragraph},
+    widgets::Paragraph,
     Frame, Terminal,
 };
 use std::io;
-use tui_slider::border::BorderStyle;
+use tui_slider::border::{BorderStyle, SliderBlock};
 use tui_slider::{symbols, Slider, SliderOrientation, SliderState};
 
 struct BorderExample {
     label: String,
     border_style: BorderStyle,
-    border_type: BorderType,
     state: SliderState,
     description: String,
     color: Color,
@@ -45,48 +44,42 @@ impl App {
                 // Colored borders with standard types
                 BorderExample {
                     label: "Cyan Theme".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Rounded,
+                    border_style: BorderStyle::Rounded,
                     state: SliderState::with_step(75.0, 0.0, 100.0, 1.0),
                     description: "Cool cyan theme".to_string(),
                     color: Color::Cyan,
                 },
                 BorderExample {
                     label: "Green Theme".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Rounded,
+                    border_style: BorderStyle::Rounded,
                     state: SliderState::with_step(60.0, 0.0, 100.0, 1.0),
                     description: "Success green".to_string(),
                     color: Color::Green,
                 },
                 BorderExample {
                     label: "Yellow Theme".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Rounded,
+                    border_style: BorderStyle::Rounded,
                     state: SliderState::with_step(45.0, 0.0, 100.0, 1.0),
                     description: "Warning yellow".to_string(),
                     color: Color::Yellow,
                 },
                 BorderExample {
                     label: "Red Theme".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Thick,
+                    border_style: BorderStyle::Thick,
                     state: SliderState::with_step(30.0, 0.0, 100.0, 1.0),
                     description: "Critical red".to_string(),
                     color: Color::Red,
                 },
                 BorderExample {
                     label: "Magenta Theme".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Rounded,
+                    border_style: BorderStyle::Rounded,
                     state: SliderState::with_step(85.0, 0.0, 100.0, 1.0),
                     description: "Creative magenta".to_string(),
                     color: Color::Magenta,
                 },
                 BorderExample {
                     label: "Custom RGB".to_string(),
-                    border_style: BorderStyle::Plain,
-                    border_type: BorderType::Rounded,
+                    border_style: BorderStyle::Rounded,
                     state: SliderState::with_step(70.0, 0.0, 100.0, 1.0),
                     description: "Custom pink".to_string(),
                     color: Color::Rgb(255, 100, 150),
@@ -95,7 +88,6 @@ impl App {
                 BorderExample {
                     label: "Plain".to_string(),
                     border_style: BorderStyle::Plain,
-                    border_type: BorderType::Plain,
                     state: SliderState::with_step(50.0, 0.0, 100.0, 1.0),
                     description: "Plain full border".to_string(),
                     color: Color::Cyan,
@@ -103,7 +95,6 @@ impl App {
                 BorderExample {
                     label: "Plain Segmented".to_string(),
                     border_style: BorderStyle::PlainSegmented,
-                    border_type: BorderType::Plain,
                     state: SliderState::with_step(55.0, 0.0, 100.0, 1.0),
                     description: "Plain with gaps".to_string(),
                     color: Color::Cyan,
@@ -111,7 +102,6 @@ impl App {
                 BorderExample {
                     label: "Plain Sides".to_string(),
                     border_style: BorderStyle::PlainSidesOnly,
-                    border_type: BorderType::Plain,
                     state: SliderState::with_step(58.0, 0.0, 100.0, 1.0),
                     description: "Left/right only".to_string(),
                     color: Color::Cyan,
@@ -120,7 +110,6 @@ impl App {
                 BorderExample {
                     label: "Rounded".to_string(),
                     border_style: BorderStyle::Rounded,
-                    border_type: BorderType::Rounded,
                     state: SliderState::with_step(65.0, 0.0, 100.0, 1.0),
                     description: "Rounded full".to_string(),
                     color: Color::Green,
@@ -128,7 +117,6 @@ impl App {
                 BorderExample {
                     label: "Rounded Segmented".to_string(),
                     border_style: BorderStyle::RoundedSegmented,
-                    border_type: BorderType::Rounded,
                     state: SliderState::with_step(70.0, 0.0, 100.0, 1.0),
                     description: "Rounded with gaps".to_string(),
                     color: Color::Green,
@@ -136,7 +124,6 @@ impl App {
                 BorderExample {
                     label: "Rounded Sides".to_string(),
                     border_style: BorderStyle::RoundedSidesOnly,
-                    border_type: BorderType::Rounded,
                     state: SliderState::with_step(73.0, 0.0, 100.0, 1.0),
                     description: "Left/right only".to_string(),
                     color: Color::Green,
@@ -145,7 +132,6 @@ impl App {
                 BorderExample {
                     label: "Double".to_string(),
                     border_style: BorderStyle::Double,
-                    border_type: BorderType::Double,
                     state: SliderState::with_step(75.0, 0.0, 100.0, 1.0),
                     description: "Double line full".to_string(),
                     color: Color::Yellow,
@@ -153,7 +139,6 @@ impl App {
                 BorderExample {
                     label: "Double Segmented".to_string(),
                     border_style: BorderStyle::DoubleSegmented,
-                    border_type: BorderType::Double,
                     state: SliderState::with_step(80.0, 0.0, 100.0, 1.0),
                     description: "Double with gaps".to_string(),
                     color: Color::Yellow,
@@ -161,7 +146,6 @@ impl App {
                 BorderExample {
                     label: "Double Sides".to_string(),
                     border_style: BorderStyle::DoubleSidesOnly,
-                    border_type: BorderType::Double,
                     state: SliderState::with_step(83.0, 0.0, 100.0, 1.0),
                     description: "Left/right only".to_string(),
                     color: Color::Yellow,
@@ -170,7 +154,6 @@ impl App {
                 BorderExample {
                     label: "Thick".to_string(),
                     border_style: BorderStyle::Thick,
-                    border_type: BorderType::Thick,
                     state: SliderState::with_step(40.0, 0.0, 100.0, 1.0),
                     description: "Thick full border".to_string(),
                     color: Color::Magenta,
@@ -178,7 +161,6 @@ impl App {
                 BorderExample {
                     label: "Thick Segmented".to_string(),
                     border_style: BorderStyle::ThickSegmented,
-                    border_type: BorderType::Thick,
                     state: SliderState::with_step(45.0, 0.0, 100.0, 1.0),
                     description: "Thick with gaps".to_string(),
                     color: Color::Magenta,
@@ -186,7 +168,6 @@ impl App {
                 BorderExample {
                     label: "Thick Sides".to_string(),
                     border_style: BorderStyle::ThickSidesOnly,
-                    border_type: BorderType::Thick,
                     state: SliderState::with_step(48.0, 0.0, 100.0, 1.0),
                     description: "Left/right only".to_string(),
                     color: Color::Magenta,
@@ -362,229 +343,38 @@ fn render_examples(f: &mut Frame, app: &App, area: Rect) {
 
         let is_selected = i == app.selected;
 
-        // Render based on border style
-        match example.border_style {
-            BorderStyle::Plain
-            | BorderStyle::Rounded
-            | BorderStyle::Double
-            | BorderStyle::Thick => {
-                // Standard full borders
-                let border_style = Style::default()
-                    .fg(if is_selected {
-                        Color::White
-                    } else {
-                        example.color
-                    })
-                    .add_modifier(if is_selected {
-                        Modifier::BOLD
-                    } else {
-                        Modifier::empty()
-                    });
-
-                let block = Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(example.border_type)
-                    .border_style(border_style)
-                    .title(format!(" {} - {} ", example.label, example.description));
-
-                let slider = Slider::from_state(&example.state)
-                    .orientation(SliderOrientation::Horizontal)
-                    .filled_symbol(symbols::FILLED_THICK_LINE)
-                    .empty_symbol(symbols::EMPTY_THIN_LINE)
-                    .handle_symbol(symbols::HANDLE_CIRCLE)
-                    .filled_color(example.color)
-                    .empty_color(Color::DarkGray)
-                    .handle_color(if is_selected {
-                        Color::White
-                    } else {
-                        example.color
-                    })
-                    .show_value(true)
-                    .show_handle(true)
-                    .block(block);
-
-                f.render_widget(slider, chunks[i + 1]);
-            }
-            BorderStyle::PlainSegmented
-            | BorderStyle::RoundedSegmented
-            | BorderStyle::DoubleSegmented
-            | BorderStyle::ThickSegmented => {
-                // Segmented borders (with gaps)
-                render_segmented_border(f, example, is_selected, chunks[i + 1]);
-            }
-            BorderStyle::PlainSidesOnly
-            | BorderStyle::RoundedSidesOnly
-            | BorderStyle::DoubleSidesOnly
-            | BorderStyle::ThickSidesOnly => {
-                // Sides-only borders
-                render_sides_only_border(f, example, is_selected, chunks[i + 1]);
-            }
-        }
+        let border_style = Style::default()
+            .fg(if is_selected {
+                Color::White
+            } else {
+                example.color
+            })
+            .add_modifier(if is_selected {
+                Modifier::BOLD
+            } else {
+                Modifier::empty()
+            });
+
+        let border = SliderBlock::new(example.border_style)
+            .style(border_style)
+            .title(format!(" {} - {} ", example.label, example.description));
+
+        let slider = Slider::from_state(&example.state)
+            .orientation(SliderOrientation::Horizontal)
+            .filled_symbol(symbols::FILLED_THICK_LINE)
+            .empty_symbol(symbols::EMPTY_THIN_LINE)
+            .handle_symbol(symbols::HANDLE_CIRCLE)
+            .filled_color(example.color)
+            .empty_color(Color::DarkGray)
+            .handle_color(if is_selected {
+                Color::White
+            } else {
+                example.color
+            })
+            .show_value(true)
+            .show_handle(true)
+            .border(border);
+
+        f.render_widget(slider, chunks[i + 1]);
     }
 }
-
-fn render_segmented_border(f: &mut Frame, example: &BorderExample, is_selected: bool, area: Rect) {
-    if area.width < 4 || area.height < 3 {
-        return;
-    }
-
-    let color = if is_selected {
-        Color::White
-    } else {
-        example.color
-    };
-    let style = Style::default().fg(color);
-
-    // Determine border characters based on type
-    let (tl, tr, bl, br, h, v) = match example.border_type {
-        BorderType::Plain => ('┌', '┐', '└', '┘', '─', '│'),
-        BorderType::Rounded => ('╭', '╮', '╰', '╯', '─', '│'),
-        BorderType::Double => ('╔', '╗', '╚', '╝', '═', '║'),
-        BorderType::Thick => ('┏', '┓', '┗', '┛', '━', '┃'),
-        _ => ('┌', '┐', '└', '┘', '─', '│'),
-    };
-
-    // Draw corners
-    f.render_widget(
-        Paragraph::new(tl.to_string()).style(style),
-        Rect::new(area.x, area.y, 1, 1),
-    );
-    f.render_widget(
-        Paragraph::new(tr.to_string()).style(style),
-        Rect::new(area.x + area.width - 1, area.y, 1, 1),
-    );
-    f.render_widget(
-        Paragraph::new(bl.to_string()).style(style),
-        Rect::new(area.x, area.y + area.height - 1, 1, 1),
-    );
-    f.render_widget(
-        Paragraph::new(br.to_string()).style(style),
-        Rect::new(area.x + area.width - 1, area.y + area.height - 1, 1, 1),
-    );
-
-    // Draw segmented top and bottom borders
-    let segment_len = 3;
-    let gap_len = 2;
-    let _inner_width = area.width.saturating_sub(2) as usize;
-
-    for i in (1..area.width - 1).step_by(segment_len + gap_len) {
-        let seg_width = segment_len.min((area.width - 1 - i) as usize);
-        let segment = h.to_string().repeat(seg_width);
-        // Top
-        f.render_widget(
-            Paragraph::new(segment.clone()).style(style),
-            Rect::new(area.x + i, area.y, seg_width as u16, 1),
-        );
-        // Bottom
-        f.render_widget(
-            Paragraph::new(segment).style(style),
-            Rect::new(area.x + i, area.y + area.height - 1, seg_width as u16, 1),
-        );
-    }
-
-    // Draw full side borders
-    for y in 1..area.height - 1 {
-        f.render_widget(
-            Paragraph::new(v.to_string()).style(style),
-            Rect::new(area.x, area.y + y, 1, 1),
-        );
-        f.render_widget(
-            Paragraph::new(v.to_string()).style(style),
-            Rect::new(area.x + area.width - 1, area.y + y, 1, 1),
-        );
-    }
-
-    // Title
-    let title = format!(" {} - {} ", example.label, example.description);
-    let title_len = title.len() as u16;
-    let title_x = (area.width.saturating_sub(title_len)) / 2;
-    f.render_widget(
-        Paragraph::new(title).style(style.add_modifier(Modifier::BOLD)),
-        Rect::new(area.x + title_x, area.y, title_len, 1),
-    );
-
-    // Draw slider inside
-    let inner = Rect::new(
-        area.x + 1,
-        area.y + 1,
-        area.width.saturating_sub(2),
-        area.height.saturating_sub(2),
-    );
-
-    let slider = Slider::from_state(&example.state)
-        .orientation(SliderOrientation::Horizontal)
-        .filled_symbol(symbols::FILLED_THICK_LINE)
-        .empty_symbol(symbols::EMPTY_THIN_LINE)
-        .handle_symbol(symbols::HANDLE_CIRCLE)
-        .filled_color(example.color)
-        .empty_color(Color::DarkGray)
-        .handle_color(color)
-        .show_value(true)
-        .show_handle(true);
-
-    f.render_widget(slider, inner);
-}
-
-fn render_sides_only_border(f: &mut Frame, example: &BorderExample, is_selected: bool, area: Rect) {
-    if area.width < 4 || area.height < 3 {
-        return;
-    }
-
-    let color = if is_selected {
-        Color::White
-    } else {
-        example.color
-    };
-    let style = Style::default().fg(color);
-
-    // Determine border characters
-    let v = match example.border_type {
-        BorderType::Plain => '│',
-        BorderType::Rounded => '│',
-        BorderType::Double => '║',
-        BorderType::Thick => '┃',
-        _ => '│',
-    };
-
-    // Draw left and right borders only
-    for y in 0..area.height {
-        f.render_widget(
-            Paragraph::new(v.to_string()).style(style),
-            Rect::new(area.x, area.y + y, 1, 1),
-        );
-        f.render_widget(
-            Paragraph::new(v.to_string()).style(style),
-            Rect::new(area.x + area.width - 1, area.y + y, 1, 1),
-        );
-    }
-
-    // Title at top
-    let title = format!(" {} - {} ", example.label, example.description);
-    let title_len = title.len() as u16;
-    let title_x = (area.width.saturating_sub(title_len)) / 2;
-    f.render_widget(
-        Paragraph::new(title).style(style.add_modifier(Modifier::BOLD)),
-        Rect::new(area.x + title_x, area.y, title_len, 1),
-    );
-
-    // Draw slider inside
-    let inner = Rect::new(
-        area.x + 1,
-        area.y + 1,
-        area.width.saturating_sub(2),
-        area.height.saturating_sub(2),
-    );
-
-    let slider = Slider::from_state(&example.state)
-        .orientation(SliderOrientation::Horizontal)
-        .filled_symbol(symbols::FILLED_THICK_LINE)
-        .empty_symbol(symbols::EMPTY_THIN_LINE)
-        .handle_symbol(symbols::HANDLE_CIRCLE)
-        .filled_color(example.color)
-        .empty_color(Color::DarkGray)
-        .handle_color(color)
-        .show_value(true)
-        .show_handle(true);
-
-    f.render_widget(slider, inner);
-}
@@ -454,11 +454,7 @@ fn render_horizontal_section(
 
         let slider = Slider::from_state(&config.state)
             .orientation(SliderOrientation::Horizontal)
-            .filled_symbol(config.style.filled_symbol)
-            .empty_symbol(config.style.empty_symbol)
-            .handle_symbol(config.style.handle_symbol)
-            .filled_color(config.style.filled_color)
-            .empty_color(config.style.empty_color)
+            .style(config.style.clone())
             .handle_color(if is_selected {
                 Color::White
             } else {
@@ -550,11 +546,7 @@ fn render_vertical_section(
         let slider = Slider::from_state(&config.state)
             .orientation(SliderOrientation::Vertical)
             .label(&config.label)
-            .filled_symbol(config.style.filled_symbol)
-            .empty_symbol(config.style.empty_symbol)
-            .handle_symbol(config.style.handle_symbol)
-            .filled_color(config.style.filled_color)
-            .empty_color(config.style.empty_color)
+            .style(config.style.clone())
             .handle_color(if is_selected {
                 Color::White
             } else {
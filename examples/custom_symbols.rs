@@ -13,7 +13,6 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame, Terminal,
 };
@@ -261,122 +260,18 @@ fn render_sliders(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             })
             .title(format!(" {} - {} Style ", label, style.name));
 
-        if style.segmented {
-            // Render segmented slider with custom logic
-            render_segmented_slider(f, state, style, is_selected, block, chunks[i + 1]);
-        } else {
-            let slider = Slider::from_state(state)
-                .orientation(SliderOrientation::Horizontal)
-                .filled_symbol(style.filled_symbol)
-                .empty_symbol(style.empty_symbol)
-                .handle_symbol(style.handle_symbol)
-                .filled_color(style.filled_color)
-                .empty_color(style.empty_color)
-                .handle_color(if is_selected {
-                    Color::White
-                } else {
-                    style.handle_color
-                })
-                .show_value(true)
-                .show_handle(true)
-                .block(block);
-
-            f.render_widget(slider, chunks[i + 1]);
-        }
-    }
-}
-
-fn render_segmented_slider(
-    f: &mut Frame,
-    state: &SliderState,
-    style: &SliderStyle,
-    is_selected: bool,
-    block: Block,
-    area: ratatui::layout::Rect,
-) {
-    let inner = block.inner(area);
-    f.render_widget(block, area);
-
-    if inner.width < 3 || inner.height < 1 {
-        return;
-    }
-
-    // Calculate segments to fill the entire width
-    let available_width = inner.width as usize;
-    let value_str = format!("{:.1}", state.value());
-    let value_width = value_str.len() + 2; // "  " + value
-    let bar_width = available_width.saturating_sub(value_width);
-
-    // Each segment is: symbol (1 width) + space (1 width) = 2 total
-    // Use half the bar width as segment count to ensure we fill the space
-    let segment_count = (bar_width / 2).max(1);
-
-    let percentage = state.percentage();
-    let filled_segments = (segment_count as f64 * percentage).round() as usize;
-
-    // Build the segmented bar - fill exactly bar_width
-    let mut segments = Vec::new();
-    let mut current_width = 0;
-
-    for i in 0..segment_count {
-        if current_width >= bar_width {
-            break;
-        }
-
-        if i < filled_segments {
-            segments.push(Span::styled(
-                style.filled_symbol,
-                Style::default().fg(style.filled_color),
-            ));
-        } else {
-            segments.push(Span::styled(
-                style.empty_symbol,
-                Style::default().fg(style.empty_color),
-            ));
-        }
-        current_width += 1;
-
-        // Add space between segments if there's room
-        if current_width < bar_width {
-            segments.push(Span::raw(" "));
-            current_width += 1;
-        }
-    }
-
-    // Fill any remaining width with spaces to ensure consistent length
-    while current_width < bar_width {
-        segments.push(Span::raw(" "));
-        current_width += 1;
-    }
+        let slider = Slider::from_state(state)
+            .orientation(SliderOrientation::Horizontal)
+            .style(style.clone())
+            .handle_color(if is_selected {
+                Color::White
+            } else {
+                style.handle_color
+            })
+            .show_value(true)
+            .show_handle(true)
+            .block(block);
 
-    // Add handle at the correct position
-    let handle_pos = (segment_count as f64 * percentage).round() as usize;
-    if handle_pos > 0 && handle_pos <= segment_count {
-        let insert_pos = (handle_pos * 2).saturating_sub(1).min(segments.len());
-        if insert_pos < segments.len() {
-            segments[insert_pos] = Span::styled(
-                style.handle_symbol,
-                Style::default().fg(if is_selected {
-                    Color::White
-                } else {
-                    style.handle_color
-                }),
-            );
-        }
+        f.render_widget(slider, chunks[i + 1]);
     }
-
-    // Add value display
-    segments.push(Span::raw("  "));
-    segments.push(Span::styled(
-        value_str,
-        Style::default().fg(if is_selected {
-            Color::Cyan
-        } else {
-            Color::Gray
-        }),
-    ));
-
-    let line = Line::from(segments);
-    let para = Paragraph::new(line).alignment(Alignment::Left);
-    f.render_widget(para, inner);
 }
@@ -12,7 +12,6 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame, Terminal,
 };
@@ -241,112 +240,25 @@ fn render_sliders(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 ),
             ]));
 
-        if style.segmented {
-            // Render segmented slider
-            render_segmented_slider(
-                f,
-                state,
-                style,
-                is_selected,
-                block,
-                slider_chunks[chunk_index],
-                i,
-            );
-        } else {
-            let inner_area = block.inner(slider_chunks[chunk_index]);
-            f.render_widget(block, slider_chunks[chunk_index]);
-
-            // Reserve 2 lines at bottom: 1 blank + 1 for value
-            let slider_height = inner_area.height.saturating_sub(2);
-
-            let slider_area = ratatui::layout::Rect {
-                x: inner_area.x,
-                y: inner_area.y,
-                width: inner_area.width,
-                height: slider_height,
-            };
-
-            // Render slider without built-in label/value
-            // Hide handle for last examples (Attack and Decay)
-            let show_handle = i < 7;
-            let slider = Slider::from_state(state)
-                .orientation(SliderOrientation::Vertical)
-                .filled_symbol(style.filled_symbol)
-                .empty_symbol(style.empty_symbol)
-                .handle_symbol(style.handle_symbol)
-                .filled_color(style.filled_color)
-                .empty_color(style.empty_color)
-                .handle_color(if is_selected {
-                    Color::White
-                } else {
-                    style.handle_color
-                })
-                .show_handle(show_handle);
-
-            f.render_widget(slider, slider_area);
-
-            // Render value at the bottom, centered
-            let value_area = ratatui::layout::Rect {
-                x: inner_area.x,
-                y: inner_area.y + slider_height + 1,
-                width: inner_area.width,
-                height: 1,
-            };
-
-            let value_text = format!("{:.0}", state.value());
-            let value_para = Paragraph::new(value_text)
-                .style(if is_selected {
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::Gray)
-                })
-                .alignment(Alignment::Center);
-
-            f.render_widget(value_para, value_area);
-        }
-    }
-}
-
-fn render_segmented_slider(
-    f: &mut Frame,
-    state: &SliderState,
-    style: &SliderStyle,
-    is_selected: bool,
-    block: Block,
-    area: ratatui::layout::Rect,
-    i: usize,
-) {
-    let inner = block.inner(area);
-    f.render_widget(block, area);
-
-    if inner.width < 1 || inner.height < 3 {
-        return;
-    }
+        let inner_area = block.inner(slider_chunks[chunk_index]);
+        f.render_widget(block, slider_chunks[chunk_index]);
 
-    // Reserve 2 lines at bottom: 1 blank + 1 for value
-    let slider_height = inner.height.saturating_sub(2);
-    let total_height = slider_height as usize;
+        // Reserve 2 lines at bottom: 1 blank + 1 for value
+        let slider_height = inner_area.height.saturating_sub(2);
 
-    if total_height < 10 {
-        // Not enough height, render non-segmented
         let slider_area = ratatui::layout::Rect {
-            x: inner.x,
-            y: inner.y,
-            width: inner.width,
+            x: inner_area.x,
+            y: inner_area.y,
+            width: inner_area.width,
             height: slider_height,
         };
 
-        // Hide handle for last 2 examples (Attack and Decay)
-        let show_handle = i < 9;
+        // Render slider without built-in label/value
+        // Hide handle for last examples (Attack and Decay)
+        let show_handle = i < 7;
         let slider = Slider::from_state(state)
             .orientation(SliderOrientation::Vertical)
-            .filled_symbol(style.filled_symbol)
-            .empty_symbol(style.empty_symbol)
-            .handle_symbol(style.handle_symbol)
-            .filled_color(style.filled_color)
-            .empty_color(style.empty_color)
+            .style(style.clone())
             .handle_color(if is_selected {
                 Color::White
             } else {
@@ -356,15 +268,16 @@ fn render_segmented_slider(
 
         f.render_widget(slider, slider_area);
 
-        // Render value at bottom
+        // Render value at the bottom, centered
         let value_area = ratatui::layout::Rect {
-            x: inner.x,
-            y: inner.y + slider_height + 1,
-            width: inner.width,
+            x: inner_area.x,
+            y: inner_area.y + slider_height + 1,
+            width: inner_area.width,
             height: 1,
         };
 
-        let value_para = Paragraph::new(format!("{:.0}", state.value()))
+        let value_text = format!("{:.0}", state.value());
+        let value_para = Paragraph::new(value_text)
             .style(if is_selected {
                 Style::default()
                     .fg(Color::Cyan)
@@ -375,79 +288,5 @@ fn render_segmented_slider(
             .alignment(Alignment::Center);
 
         f.render_widget(value_para, value_area);
-        return;
     }
-
-    let percentage = state.percentage();
-
-    // Render segments with spaces to fill entire height
-    // Each segment takes 2 lines: symbol + space
-    let num_segments = total_height / 2;
-    let filled_segments = (num_segments as f64 * percentage).round() as usize;
-    let handle_position = filled_segments.saturating_sub(1);
-
-    // Build the segmented bar from top to bottom (to match visual order)
-    let mut lines = Vec::new();
-
-    for seg_i in (0..num_segments).rev() {
-        // Add handle at the correct position
-        if seg_i == handle_position && filled_segments > 0 && i < 9 {
-            lines.push(Line::from(vec![Span::styled(
-                style.handle_symbol,
-                Style::default().fg(if is_selected {
-                    Color::White
-                } else {
-                    style.handle_color
-                }),
-            )]));
-        } else {
-            let symbol = if seg_i < filled_segments {
-                Span::styled(style.filled_symbol, Style::default().fg(style.filled_color))
-            } else {
-                Span::styled(style.empty_symbol, Style::default().fg(style.empty_color))
-            };
-            lines.push(Line::from(vec![symbol]));
-        }
-
-        // Add space after each segment (except last one)
-        if lines.len() < total_height {
-            lines.push(Line::from(vec![Span::raw(" ")]));
-        }
-    }
-
-    // Fill any remaining height
-    while lines.len() < total_height {
-        lines.push(Line::from(vec![Span::raw(" ")]));
-    }
-
-    // Render the slider lines in the slider area
-    let slider_area = ratatui::layout::Rect {
-        x: inner.x,
-        y: inner.y,
-        width: inner.width,
-        height: total_height as u16,
-    };
-
-    let para = Paragraph::new(lines).alignment(Alignment::Center);
-    f.render_widget(para, slider_area);
-
-    // Render value at bottom
-    let value_area = ratatui::layout::Rect {
-        x: inner.x,
-        y: inner.y + total_height as u16 + 1,
-        width: inner.width,
-        height: 1,
-    };
-
-    let value_para = Paragraph::new(format!("{:.0}", state.value()))
-        .style(if is_selected {
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::Gray)
-        })
-        .alignment(Alignment::Center);
-
-    f.render_widget(value_para, value_area);
 }
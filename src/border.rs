@@ -18,6 +18,21 @@
 //! // Create a centered title
 //! let alignment = TitleAlignment::Center;
 //! ```
+//!
+//! ## Drawing borders around a slider
+//!
+//! ```rust
+//! use tui_slider::border::{BorderStyle, SliderBlock, TitleAlignment};
+//! use tui_slider::Slider;
+//!
+//! let slider = Slider::new(40.0, 0.0, 100.0).border(
+//!     SliderBlock::new(BorderStyle::RoundedSegmented)
+//!         .title(" Volume ")
+//!         .title_alignment(TitleAlignment::Left),
+//! );
+//! ```
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::Widget};
 
 /// Title alignment options for block borders
 ///
@@ -303,6 +318,182 @@ impl BorderStyle {
     }
 }
 
+/// A border drawn in one of the [`BorderStyle`] variants, with an optional title
+///
+/// Unlike a ratatui `Block`, this draws segmented edges and left/right-only
+/// borders. Every style reserves one cell on each side, so switching styles
+/// does not move the content; sides-only styles leave the top and bottom rows
+/// blank apart from the title.
+///
+/// Use it through [`Slider::border`](crate::Slider::border) or render it on its
+/// own and draw into [`inner`](Self::inner).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use tui_slider::border::{BorderStyle, SliderBlock};
+///
+/// let block = SliderBlock::new(BorderStyle::PlainSegmented).title("Gain");
+/// let area = Rect::new(0, 0, 10, 3);
+/// let mut buf = Buffer::empty(area);
+/// block.render(area, &mut buf);
+///
+/// assert_eq!(buf[(0, 0)].symbol(), "┌");
+/// assert_eq!(buf[(0, 1)].symbol(), "│");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SliderBlock<'a> {
+    /// Border variant to draw
+    border: BorderStyle,
+    /// Style of the border characters
+    style: Style,
+    /// Title drawn on the border
+    title: Option<Line<'a>>,
+    /// Horizontal alignment of the title
+    title_alignment: TitleAlignment,
+    /// Edge the title is drawn on
+    title_position: TitlePosition,
+}
+
+impl<'a> SliderBlock<'a> {
+    /// Create a block with the given border style
+    pub fn new(border: BorderStyle) -> Self {
+        Self {
+            border,
+            ..Self::default()
+        }
+    }
+
+    /// Set the style of the border characters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::border::{BorderStyle, SliderBlock};
+    ///
+    /// let block = SliderBlock::new(BorderStyle::Thick).style(Style::new().fg(Color::Cyan));
+    /// ```
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the title drawn on the border
+    ///
+    /// The title takes the border style unless its spans set their own. It is
+    /// cut off where it would reach the corners.
+    pub fn title<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the horizontal alignment of the title
+    pub fn title_alignment(mut self, alignment: TitleAlignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Set the edge the title is drawn on
+    pub fn title_position(mut self, position: TitlePosition) -> Self {
+        self.title_position = position;
+        self
+    }
+
+    /// Get the area inside the border
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Rect;
+    /// use tui_slider::border::{BorderStyle, SliderBlock};
+    ///
+    /// let block = SliderBlock::new(BorderStyle::DoubleSidesOnly);
+    /// assert_eq!(block.inner(Rect::new(0, 0, 10, 4)), Rect::new(1, 1, 8, 2));
+    /// ```
+    pub fn inner(&self, area: Rect) -> Rect {
+        Rect::new(
+            area.x.saturating_add(1),
+            area.y.saturating_add(1),
+            area.width.saturating_sub(2),
+            area.height.saturating_sub(2),
+        )
+    }
+
+    /// Draw the title on its edge, between the corners
+    fn render_title(&self, area: Rect, buf: &mut Buffer) {
+        let Some(ref title) = self.title else {
+            return;
+        };
+        let available = area.width.saturating_sub(2);
+        let width = (title.width() as u16).min(available);
+        let x = match self.title_alignment {
+            TitleAlignment::Left => area.x + 1,
+            TitleAlignment::Center => area.x + 1 + (available - width) / 2,
+            TitleAlignment::Right => area.x + 1 + available - width,
+        };
+        let y = match self.title_position {
+            TitlePosition::Top => area.y,
+            TitlePosition::Bottom => area.bottom() - 1,
+        };
+        buf.set_line(x, y, title, width);
+    }
+}
+
+impl From<BorderStyle> for SliderBlock<'_> {
+    fn from(border: BorderStyle) -> Self {
+        Self::new(border)
+    }
+}
+
+impl Widget for SliderBlock<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &SliderBlock<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+        let set = self.border.border_set();
+        let (left, right) = (area.x, area.right() - 1);
+        let (top, bottom) = (area.y, area.bottom() - 1);
+        let vertical = set.vertical.to_string();
+
+        if set.sides_only {
+            for y in top..=bottom {
+                buf.set_string(left, y, &vertical, self.style);
+                buf.set_string(right, y, &vertical, self.style);
+            }
+        } else {
+            let inner_width = (right - left - 1) as usize;
+            let horizontal = if set.segmented {
+                create_segmented_line(inner_width, set.horizontal)
+            } else {
+                set.horizontal.to_string().repeat(inner_width)
+            };
+            for (y, start, end) in [
+                (top, set.top_left, set.top_right),
+                (bottom, set.bottom_left, set.bottom_right),
+            ] {
+                buf.set_string(left, y, start.to_string(), self.style);
+                buf.set_string(left + 1, y, &horizontal, self.style);
+                buf.set_string(right, y, end.to_string(), self.style);
+            }
+            for y in top + 1..bottom {
+                buf.set_string(left, y, &vertical, self.style);
+                buf.set_string(right, y, &vertical, self.style);
+            }
+        }
+
+        self.render_title(area, buf);
+    }
+}
+
 /// Create a segmented line with gaps
 ///
 /// Creates a string with a pattern of characters and spaces (2 chars on, 1 space off)
@@ -414,6 +605,9 @@ pub fn title_right(text: impl Into<String>) -> ratatui::widgets::block::Title<'s
 /// This variant adds trailing spaces to prevent collision with values that may be
 /// rendered on the same line. Use this when you have both a title and a separate value display.
 ///
+/// To show the value in the title itself, use
+/// [`Slider::border_title`](crate::Slider::border_title) instead.
+///
 /// # Examples
 ///
 /// ```rust
@@ -430,6 +624,35 @@ pub fn title_right_with_spacing(
     create_title(text_with_spacing, Some(TitleAlignment::Right), None)
 }
 
+/// Expand the `{label}`, `{value}` and `{percent}` placeholders of a title template
+///
+/// Unknown placeholders and unmatched braces are kept as written.
+pub(crate) fn expand_title_template(
+    template: &str,
+    label: &str,
+    value: &str,
+    percent: &str,
+) -> String {
+    let mut title = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        title.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        match &rest[1..end] {
+            "label" => title.push_str(label),
+            "value" => title.push_str(value),
+            "percent" => title.push_str(percent),
+            _ => title.push_str(&rest[..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    title.push_str(rest);
+    title
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -492,6 +715,82 @@ mod tests {
         assert_eq!(styles.len(), 12);
     }
 
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn render_block(block: SliderBlock, width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        block.render(area, &mut buf);
+        rows(&buf)
+    }
+
+    #[test]
+    fn test_slider_block_full() {
+        let rows = render_block(SliderBlock::new(BorderStyle::Rounded), 5, 3);
+        assert_eq!(rows, ["╭───╮", "│   │", "╰───╯"]);
+    }
+
+    #[test]
+    fn test_slider_block_segmented() {
+        let rows = render_block(SliderBlock::new(BorderStyle::ThickSegmented), 8, 3);
+        assert_eq!(rows, ["┏━━ ━━ ┓", "┃      ┃", "┗━━ ━━ ┛"]);
+    }
+
+    #[test]
+    fn test_slider_block_sides_only() {
+        let rows = render_block(SliderBlock::new(BorderStyle::DoubleSidesOnly), 5, 3);
+        assert_eq!(rows, ["║   ║", "║   ║", "║   ║"]);
+    }
+
+    #[test]
+    fn test_slider_block_title() {
+        let block = SliderBlock::new(BorderStyle::Plain).title("Vol");
+        assert_eq!(render_block(block, 9, 3)[0], "┌──Vol──┐");
+
+        let block = SliderBlock::new(BorderStyle::PlainSidesOnly)
+            .title("Volume")
+            .title_alignment(TitleAlignment::Right)
+            .title_position(TitlePosition::Bottom);
+        // Cut off before the corners
+        assert_eq!(render_block(block, 6, 3)[2], "│Volu│");
+    }
+
+    #[test]
+    fn test_slider_block_every_style() {
+        for &style in BorderStyle::all() {
+            let rows = render_block(SliderBlock::new(style), 6, 3);
+            let set = style.border_set();
+            assert_eq!(rows[1].chars().next(), Some(set.vertical), "{style:?}");
+            assert_eq!(rows[1].chars().last(), Some(set.vertical), "{style:?}");
+            let corner = if set.sides_only {
+                set.vertical
+            } else {
+                set.top_left
+            };
+            assert_eq!(rows[0].chars().next(), Some(corner), "{style:?}");
+        }
+    }
+
+    #[test]
+    fn test_expand_title_template() {
+        assert_eq!(
+            expand_title_template(" {label} — {percent} ", "Volume", "72.0", "72%"),
+            " Volume — 72% "
+        );
+        assert_eq!(
+            expand_title_template("{value}{unit} {", "", "3", ""),
+            "3{unit} {"
+        );
+    }
+
     #[test]
     fn test_sides_only_variants() {
         assert!(BorderStyle::PlainSidesOnly.is_sides_only());
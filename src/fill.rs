@@ -0,0 +1,209 @@
+//! Fill mode module
+//!
+//! This module defines how the filled portion of a slider track is drawn.
+
+use crate::{
+    orientation::SliderOrientation,
+    symbols::{EMPTY_BRAILLE, EMPTY_BRAILLE_VERTICAL, FILLED_BRAILLE},
+};
+
+/// First code point of the braille patterns block; the low byte holds the dots
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// Dot bits of each braille dot column, left to right
+const BRAILLE_COLUMNS: [u8; 2] = [0x47, 0xB8];
+
+/// Dot bits of each braille dot row, bottom to top
+const BRAILLE_ROWS: [u8; 4] = [0xC0, 0x24, 0x12, 0x09];
+
+/// How the filled portion of the track is drawn
+///
+/// # Examples
+///
+/// ```
+/// use tui_slider::{FillMode, Slider};
+///
+/// let slider = Slider::default().fill_mode(FillMode::Eighths);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillMode {
+    /// Repeat the filled and empty symbols, one level per cell
+    #[default]
+    Symbols,
+    /// Full blocks with a partial block at the boundary cell, eight levels per cell
+    Eighths,
+    /// Braille dots filled one dot line at a time, with a one-dot handle
+    ///
+    /// Each cell holds two dot columns and four dot rows, so horizontal tracks
+    /// have two levels per cell and vertical tracks four.
+    Braille,
+}
+
+impl FillMode {
+    /// Returns the number of distinct fill levels a single cell can show
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{FillMode, SliderOrientation};
+    ///
+    /// assert_eq!(FillMode::Eighths.levels_per_cell(SliderOrientation::Horizontal), 8);
+    /// assert_eq!(FillMode::Braille.levels_per_cell(SliderOrientation::Vertical), 4);
+    /// ```
+    pub fn levels_per_cell(&self, orientation: SliderOrientation) -> usize {
+        match (self, orientation) {
+            (Self::Symbols, _) => 1,
+            (Self::Eighths, _) => 8,
+            (Self::Braille, SliderOrientation::Horizontal) => BRAILLE_COLUMNS.len(),
+            (Self::Braille, SliderOrientation::Vertical) => BRAILLE_ROWS.len(),
+        }
+    }
+
+    /// Splits a fill over `cells` cells into whole cells and remaining levels
+    ///
+    /// The remainder is always below [`levels_per_cell`](Self::levels_per_cell).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{FillMode, SliderOrientation};
+    ///
+    /// let horizontal = SliderOrientation::Horizontal;
+    /// assert_eq!(FillMode::Eighths.split(10, 0.55, horizontal), (5, 4));
+    /// assert_eq!(FillMode::Symbols.split(10, 0.55, horizontal), (5, 0));
+    /// ```
+    pub fn split(
+        &self,
+        cells: usize,
+        percentage: f64,
+        orientation: SliderOrientation,
+    ) -> (usize, usize) {
+        let levels = self.levels_per_cell(orientation);
+        let filled = self.filled_levels(cells, percentage, orientation);
+        (filled / levels, filled % levels)
+    }
+
+    /// Returns the total number of filled levels over `cells` cells
+    pub(crate) fn filled_levels(
+        &self,
+        cells: usize,
+        percentage: f64,
+        orientation: SliderOrientation,
+    ) -> usize {
+        let levels = self.levels_per_cell(orientation);
+        let total = cells * levels;
+        let percentage = percentage.clamp(0.0, 1.0);
+        match self {
+            // Whole cells only, matching the truncation of the symbol renderer
+            Self::Symbols => (cells as f64 * percentage) as usize * levels,
+            _ => ((total as f64 * percentage).round() as usize).min(total),
+        }
+    }
+}
+
+/// Builds the braille glyph for one cell of a braille track
+///
+/// `filled` is the number of dot lines filled from the start of the cell (left for
+/// horizontal, bottom for vertical) and `handle` the dot line drawn as the handle.
+/// Unfilled lines show the dots of the empty track.
+pub(crate) fn braille_cell(
+    filled: usize,
+    handle: Option<usize>,
+    orientation: SliderOrientation,
+) -> char {
+    let (lines, empty): (&[u8], _) = match orientation {
+        SliderOrientation::Horizontal => (&BRAILLE_COLUMNS, braille_dots(EMPTY_BRAILLE)),
+        SliderOrientation::Vertical => (&BRAILLE_ROWS, braille_dots(EMPTY_BRAILLE_VERTICAL)),
+    };
+    let full = braille_dots(FILLED_BRAILLE);
+
+    let dots = lines.iter().enumerate().fold(0u8, |dots, (line, &mask)| {
+        if line < filled || handle == Some(line) {
+            dots | (full & mask)
+        } else {
+            dots | (empty & mask)
+        }
+    });
+
+    char::from_u32(BRAILLE_BASE + u32::from(dots)).unwrap_or(' ')
+}
+
+/// Returns the dot bits of a braille symbol, or no dots for anything else
+fn braille_dots(symbol: &str) -> u8 {
+    symbol
+        .chars()
+        .next()
+        .and_then(|c| u32::from(c).checked_sub(BRAILLE_BASE))
+        .and_then(|dots| u8::try_from(dots).ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HORIZONTAL: SliderOrientation = SliderOrientation::Horizontal;
+    const VERTICAL: SliderOrientation = SliderOrientation::Vertical;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(FillMode::default(), FillMode::Symbols);
+    }
+
+    #[test]
+    fn test_levels_per_cell() {
+        assert_eq!(FillMode::Symbols.levels_per_cell(HORIZONTAL), 1);
+        assert_eq!(FillMode::Eighths.levels_per_cell(HORIZONTAL), 8);
+        assert_eq!(FillMode::Eighths.levels_per_cell(VERTICAL), 8);
+        assert_eq!(FillMode::Braille.levels_per_cell(HORIZONTAL), 2);
+        assert_eq!(FillMode::Braille.levels_per_cell(VERTICAL), 4);
+    }
+
+    #[test]
+    fn test_split_eighths() {
+        assert_eq!(FillMode::Eighths.split(20, 0.0, HORIZONTAL), (0, 0));
+        assert_eq!(FillMode::Eighths.split(20, 1.0, HORIZONTAL), (20, 0));
+        // 1/160 of the track is a single eighth
+        assert_eq!(FillMode::Eighths.split(20, 1.0 / 160.0, HORIZONTAL), (0, 1));
+        assert_eq!(
+            FillMode::Eighths.split(20, 0.5 + 3.0 / 160.0, HORIZONTAL),
+            (10, 3)
+        );
+    }
+
+    #[test]
+    fn test_split_braille() {
+        assert_eq!(FillMode::Braille.split(10, 0.55, HORIZONTAL), (5, 1));
+        assert_eq!(FillMode::Braille.split(10, 0.55, VERTICAL), (5, 2));
+    }
+
+    #[test]
+    fn test_split_clamps() {
+        assert_eq!(FillMode::Eighths.split(4, 1.5, HORIZONTAL), (4, 0));
+        assert_eq!(FillMode::Eighths.split(4, -0.5, HORIZONTAL), (0, 0));
+        assert_eq!(FillMode::Symbols.split(4, 0.99, HORIZONTAL), (3, 0));
+    }
+
+    #[test]
+    fn test_braille_cell_horizontal() {
+        assert_eq!(braille_cell(0, None, HORIZONTAL), '⣀');
+        assert_eq!(braille_cell(1, None, HORIZONTAL), '⣇');
+        assert_eq!(braille_cell(2, None, HORIZONTAL), '⣿');
+        assert_eq!(braille_cell(0, Some(1), HORIZONTAL), '⣸');
+    }
+
+    #[test]
+    fn test_braille_cell_vertical() {
+        assert_eq!(braille_cell(0, None, VERTICAL), '⡇');
+        assert_eq!(braille_cell(1, None, VERTICAL), '⣇');
+        assert_eq!(braille_cell(3, None, VERTICAL), '⣷');
+        assert_eq!(braille_cell(4, None, VERTICAL), '⣿');
+        assert_eq!(braille_cell(0, Some(3), VERTICAL), '⡏');
+    }
+
+    #[test]
+    fn test_braille_dots() {
+        assert_eq!(braille_dots(FILLED_BRAILLE), 0xFF);
+        assert_eq!(braille_dots("x"), 0);
+    }
+}
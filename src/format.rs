@@ -0,0 +1,715 @@
+//! Value formatting module
+//!
+//! This module provides [`ValueFormatter`], used to turn slider values into the
+//! text shown next to the bar and on tick labels. Any `Fn(f64) -> String`
+//! closure is a formatter; the built-in formatters cover decimals, percentages,
+//! units, SI prefixes and durations.
+//!
+//! Numbers are written with a [`NumberFormat`], which sets the decimal
+//! separator, digit grouping and sign style for locales that do not use
+//! `12,345.6`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tui_slider::format::{NumberFormat, Percent, Unit};
+//! use tui_slider::Slider;
+//!
+//! // "72%"
+//! let slider = Slider::new(72.0, 0.0, 100.0)
+//!     .show_value(true)
+//!     .value_formatter(Percent::new(0));
+//!
+//! // "-6.0 dB"
+//! let slider = Slider::new(-6.0, -60.0, 0.0)
+//!     .show_value(true)
+//!     .value_formatter(Unit::new(1, " dB"));
+//!
+//! // Any closure works too
+//! let slider = Slider::new(3.0, 0.0, 10.0)
+//!     .show_value(true)
+//!     .value_formatter(|value: f64| format!("{value:.0}x"));
+//!
+//! // "10 000,5 Hz"
+//! let slider = Slider::new(10_000.5, 20.0, 20_000.0)
+//!     .show_value(true)
+//!     .value_formatter(Unit::new(1, " Hz"))
+//!     .number_format(NumberFormat::new().decimal_separator(',').grouping_separator(' '));
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+/// SI prefixes from pico to tera, in steps of a thousand
+const SI_PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+
+/// Index of the empty prefix in [`SI_PREFIXES`]
+const SI_UNIT_INDEX: i32 = 4;
+
+/// Default number of digits per group
+const DEFAULT_GROUPING_SIZE: usize = 3;
+
+/// How the sign of a number is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignStyle {
+    /// A minus sign for negative numbers only, such as `-5`
+    #[default]
+    Negative,
+    /// A plus sign for positive numbers and zero too, such as `+5` and `-5`
+    Always,
+    /// Negative numbers in parentheses, such as `(5)`
+    Parentheses,
+}
+
+/// Locale settings for writing numbers
+///
+/// The default writes numbers the way `format!` does: `.` as the decimal
+/// separator, no grouping and a minus sign for negative numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{NumberFormat, SignStyle};
+///
+/// let german = NumberFormat::new().decimal_separator(',').grouping_separator('.');
+/// assert_eq!(german.format(12345.5, 1), "12.345,5");
+///
+/// let french = NumberFormat::new().decimal_separator(',').grouping_separator(' ');
+/// assert_eq!(french.format(-10000.0, 0), "-10 000");
+///
+/// let gain = NumberFormat::new().sign(SignStyle::Always);
+/// assert_eq!(gain.format(3.0, 1), "+3.0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Separator between the integer and fractional parts
+    decimal_separator: char,
+    /// Separator between digit groups, if grouping is enabled
+    grouping_separator: Option<char>,
+    /// Number of digits per group
+    grouping_size: usize,
+    /// How the sign is written
+    sign: SignStyle,
+}
+
+impl NumberFormat {
+    /// Creates a number format matching `format!`
+    pub fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: None,
+            grouping_size: DEFAULT_GROUPING_SIZE,
+            sign: SignStyle::default(),
+        }
+    }
+
+    /// Sets the separator between the integer and fractional parts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::NumberFormat;
+    ///
+    /// let format = NumberFormat::new().decimal_separator(',');
+    /// assert_eq!(format.format(12.5, 1), "12,5");
+    /// ```
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Enables digit grouping with the given separator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::NumberFormat;
+    ///
+    /// let format = NumberFormat::new().grouping_separator(',');
+    /// assert_eq!(format.format(1234567.0, 0), "1,234,567");
+    /// ```
+    pub fn grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = Some(separator);
+        self
+    }
+
+    /// Sets the number of digits per group, three by default
+    ///
+    /// A size of zero disables grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::NumberFormat;
+    ///
+    /// let format = NumberFormat::new().grouping_separator(' ').grouping_size(4);
+    /// assert_eq!(format.format(1234567.0, 0), "123 4567");
+    /// ```
+    pub fn grouping_size(mut self, size: usize) -> Self {
+        self.grouping_size = size;
+        self
+    }
+
+    /// Sets how the sign is written
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::{NumberFormat, SignStyle};
+    ///
+    /// let format = NumberFormat::new().sign(SignStyle::Parentheses);
+    /// assert_eq!(format.format(-4.25, 2), "(4.25)");
+    /// ```
+    pub fn sign(mut self, sign: SignStyle) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Writes `value` with `decimals` decimals
+    pub fn format(&self, value: f64, decimals: usize) -> String {
+        let plain = format!("{:.*}", decimals, value);
+        let (negative, digits) = match plain.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, plain.as_str()),
+        };
+        // inf and NaN are left as they are
+        if !value.is_finite() {
+            return plain;
+        }
+
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+        let mut number = self.group(integer);
+        if let Some(fraction) = fraction {
+            number.push(self.decimal_separator);
+            number.push_str(fraction);
+        }
+
+        match (self.sign, negative) {
+            (SignStyle::Parentheses, true) => format!("({number})"),
+            (_, true) => format!("-{number}"),
+            (SignStyle::Always, false) => format!("+{number}"),
+            (_, false) => number,
+        }
+    }
+
+    /// Writes the separator editors accept, for pre-filling an edit field
+    ///
+    /// Only `.` and `,` are accepted when typing, so other decimal separators
+    /// fall back to `.`.
+    pub(crate) fn format_editable(&self, value: f64, decimals: usize) -> String {
+        let plain = format!("{:.*}", decimals, value);
+        if self.decimal_separator == ',' {
+            plain.replace('.', ",")
+        } else {
+            plain
+        }
+    }
+
+    /// Reads a number typed into an edit field
+    ///
+    /// The decimal separator is the configured one, or `.` when that cannot be
+    /// typed. The grouping separator may appear in the integer part, but only
+    /// between full groups. Any other `.` or `,` makes the text ambiguous, so
+    /// `None` is returned rather than guessing.
+    pub(crate) fn parse_editable(&self, text: &str) -> Option<f64> {
+        let decimal = match self.decimal_separator {
+            separator @ ('.' | ',') => separator,
+            _ => '.',
+        };
+        let grouping = self
+            .grouping_separator
+            .filter(|&separator| self.grouping_size > 0 && separator != decimal);
+
+        let (sign, unsigned) = match text.strip_prefix(['-', '+']) {
+            Some(rest) => (&text[..1], rest),
+            None => ("", text),
+        };
+        let (integer, fraction) = match unsigned.split_once(decimal) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let integer = match grouping {
+            Some(separator) if integer.contains(separator) => {
+                let mut groups = integer.split(separator);
+                let first = groups.next()?;
+                let valid = (1..=self.grouping_size).contains(&first.len())
+                    && groups.all(|group| group.len() == self.grouping_size);
+                if !valid {
+                    return None;
+                }
+                integer.replace(separator, "")
+            }
+            _ => integer.to_string(),
+        };
+        let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if !digits(&integer) || !fraction.map_or(true, digits) {
+            return None;
+        }
+        if integer.is_empty() && fraction.map_or(true, str::is_empty) {
+            return None;
+        }
+
+        let number = match fraction {
+            Some(fraction) => format!("{sign}{integer}.{fraction}"),
+            None => format!("{sign}{integer}"),
+        };
+        number.parse().ok()
+    }
+
+    /// Inserts the grouping separator into a run of integer digits
+    fn group(&self, integer: &str) -> String {
+        let separator = match self.grouping_separator {
+            Some(separator) if self.grouping_size > 0 => separator,
+            _ => return integer.to_string(),
+        };
+        let mut grouped = String::with_capacity(integer.len() * 2);
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % self.grouping_size == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Range and number format a value is formatted in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormatContext {
+    /// Minimum value of the slider
+    min: f64,
+    /// Maximum value of the slider
+    max: f64,
+    /// How numbers are written
+    number_format: NumberFormat,
+}
+
+impl FormatContext {
+    /// Creates a context for a slider ranging from `min` to `max`
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            number_format: NumberFormat::new(),
+        }
+    }
+
+    /// Sets how numbers are written
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Writes a number with `decimals` decimals using the number format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::{FormatContext, NumberFormat};
+    ///
+    /// let context = FormatContext::new(0.0, 100.0)
+    ///     .number_format(NumberFormat::new().decimal_separator(','));
+    /// assert_eq!(context.format_number(12.5, 1), "12,5");
+    /// ```
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        self.number_format.format(value, decimals)
+    }
+
+    /// Returns the minimum value of the slider
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value of the slider
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the position of `value` in the range, from 0.0 to 1.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::FormatContext;
+    ///
+    /// let context = FormatContext::new(-60.0, 0.0);
+    /// assert_eq!(context.percentage(-15.0), 0.75);
+    /// ```
+    pub fn percentage(&self, value: f64) -> f64 {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            0.0
+        } else {
+            ((value - self.min) / range).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Turns a slider value into display text
+///
+/// Implemented for every `Fn(f64) -> String + Send + Sync` closure. Implement it
+/// directly when the text depends on the slider's range, or to honor the
+/// [`NumberFormat`] through [`FormatContext::format_number`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{FormatContext, ValueFormatter};
+///
+/// struct OutOf;
+///
+/// impl ValueFormatter for OutOf {
+///     fn format(&self, value: f64, context: &FormatContext) -> String {
+///         format!("{value:.0}/{:.0}", context.max())
+///     }
+/// }
+///
+/// assert_eq!(OutOf.format(3.0, &FormatContext::new(0.0, 5.0)), "3/5");
+/// ```
+pub trait ValueFormatter: Send + Sync {
+    /// Formats `value` for a slider with the given range
+    fn format(&self, value: f64, context: &FormatContext) -> String;
+}
+
+impl<F> ValueFormatter for F
+where
+    F: Fn(f64) -> String + Send + Sync,
+{
+    fn format(&self, value: f64, _context: &FormatContext) -> String {
+        self(value)
+    }
+}
+
+/// Shared handle to a formatter, cheap to clone with the widget or state
+#[derive(Clone)]
+pub(crate) struct SharedFormatter(Arc<dyn ValueFormatter>);
+
+impl SharedFormatter {
+    /// Wraps a formatter
+    pub(crate) fn new<F: ValueFormatter + 'static>(formatter: F) -> Self {
+        Self(Arc::new(formatter))
+    }
+
+    /// Formats `value` in the given context
+    pub(crate) fn format(&self, value: f64, context: &FormatContext) -> String {
+        self.0.format(value, context)
+    }
+}
+
+impl fmt::Debug for SharedFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedFormatter(..)")
+    }
+}
+
+/// Formats the value with a fixed number of decimals
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{Decimals, FormatContext, ValueFormatter};
+///
+/// let context = FormatContext::new(0.0, 100.0);
+/// assert_eq!(Decimals::new(2).format(12.5, &context), "12.50");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimals {
+    /// Number of decimals
+    decimals: usize,
+}
+
+impl Decimals {
+    /// Creates a formatter printing `decimals` decimals
+    pub fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl ValueFormatter for Decimals {
+    fn format(&self, value: f64, context: &FormatContext) -> String {
+        context.format_number(value, self.decimals)
+    }
+}
+
+/// Formats the position in the range as a percentage
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{FormatContext, Percent, ValueFormatter};
+///
+/// let context = FormatContext::new(-60.0, 0.0);
+/// assert_eq!(Percent::new(0).format(-15.0, &context), "75%");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percent {
+    /// Number of decimals
+    decimals: usize,
+}
+
+impl Percent {
+    /// Creates a formatter printing percentages with `decimals` decimals
+    pub fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl ValueFormatter for Percent {
+    fn format(&self, value: f64, context: &FormatContext) -> String {
+        let percent = context.percentage(value) * 100.0;
+        format!("{}%", context.format_number(percent, self.decimals))
+    }
+}
+
+/// Formats the value followed by a unit suffix
+///
+/// The suffix is appended as given, so include a leading space if one is wanted.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{FormatContext, Unit, ValueFormatter};
+///
+/// let context = FormatContext::new(-60.0, 0.0);
+/// assert_eq!(Unit::new(1, " dB").format(-6.0, &context), "-6.0 dB");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    /// Number of decimals
+    decimals: usize,
+    /// Text appended to the number
+    suffix: String,
+}
+
+impl Unit {
+    /// Creates a formatter printing `decimals` decimals followed by `suffix`
+    pub fn new<S: Into<String>>(decimals: usize, suffix: S) -> Self {
+        Self {
+            decimals,
+            suffix: suffix.into(),
+        }
+    }
+}
+
+impl ValueFormatter for Unit {
+    fn format(&self, value: f64, context: &FormatContext) -> String {
+        format!(
+            "{}{}",
+            context.format_number(value, self.decimals),
+            self.suffix
+        )
+    }
+}
+
+/// Formats the value scaled to an SI prefix, such as `1.2 kHz`
+///
+/// Prefixes range from pico (`p`) to tera (`T`).
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{FormatContext, SiPrefix, ValueFormatter};
+///
+/// let context = FormatContext::new(20.0, 20_000.0);
+/// let hertz = SiPrefix::new(1, "Hz");
+/// assert_eq!(hertz.format(1200.0, &context), "1.2 kHz");
+/// assert_eq!(hertz.format(440.0, &context), "440.0 Hz");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiPrefix {
+    /// Number of decimals of the scaled value
+    decimals: usize,
+    /// Unit following the prefix
+    unit: String,
+}
+
+impl SiPrefix {
+    /// Creates a formatter printing `decimals` decimals followed by a prefixed `unit`
+    pub fn new<S: Into<String>>(decimals: usize, unit: S) -> Self {
+        Self {
+            decimals,
+            unit: unit.into(),
+        }
+    }
+}
+
+impl ValueFormatter for SiPrefix {
+    fn format(&self, value: f64, context: &FormatContext) -> String {
+        let max_index = SI_PREFIXES.len() as i32 - 1;
+        let mut index = if value == 0.0 || !value.is_finite() {
+            SI_UNIT_INDEX
+        } else {
+            ((value.abs().log10() / 3.0).floor() as i32 + SI_UNIT_INDEX).clamp(0, max_index)
+        };
+
+        let scale = |index: i32| value / 1000f64.powi(index - SI_UNIT_INDEX);
+        // Rounding can carry into the next prefix, e.g. 999.96 -> "1000.0"
+        let rounded = format!("{:.*}", self.decimals, scale(index));
+        if index < max_index && rounded.trim_start_matches('-').starts_with("1000") {
+            index += 1;
+        }
+        let number = context.format_number(scale(index), self.decimals);
+
+        let suffix = format!("{}{}", SI_PREFIXES[index as usize], self.unit);
+        if suffix.is_empty() {
+            number
+        } else {
+            format!("{number} {suffix}")
+        }
+    }
+}
+
+/// Formats a value in seconds as `mm:ss`, or `h:mm:ss` for long ranges
+///
+/// Hours are shown when the value or either end of the range reaches an hour,
+/// so the text keeps its width while the slider moves.
+///
+/// # Examples
+///
+/// ```rust
+/// use tui_slider::format::{Clock, FormatContext, ValueFormatter};
+///
+/// let song = FormatContext::new(0.0, 240.0);
+/// assert_eq!(Clock::new().format(195.0, &song), "03:15");
+///
+/// let film = FormatContext::new(0.0, 7200.0);
+/// assert_eq!(Clock::new().format(195.0, &film), "0:03:15");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Clock;
+
+impl Clock {
+    /// Creates a duration formatter
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ValueFormatter for Clock {
+    fn format(&self, value: f64, context: &FormatContext) -> String {
+        let total = value.abs().round() as u64;
+        let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+        let sign = if value < 0.0 && total > 0 { "-" } else { "" };
+
+        let longest = context.min.abs().max(context.max.abs());
+        if hours > 0 || longest >= 3600.0 {
+            format!("{sign}{hours}:{minutes:02}:{seconds:02}")
+        } else {
+            format!("{sign}{minutes:02}:{seconds:02}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> FormatContext {
+        FormatContext::new(0.0, 100.0)
+    }
+
+    #[test]
+    fn test_closure_formatter() {
+        let formatter = |value: f64| format!("{value:.0} pts");
+        assert_eq!(formatter.format(42.4, &context()), "42 pts");
+    }
+
+    #[test]
+    fn test_percent_uses_range() {
+        let context = FormatContext::new(50.0, 150.0);
+        assert_eq!(Percent::new(0).format(50.0, &context), "0%");
+        assert_eq!(Percent::new(1).format(125.0, &context), "75.0%");
+    }
+
+    #[test]
+    fn test_si_prefix() {
+        let volts = SiPrefix::new(1, "V");
+        assert_eq!(volts.format(0.0, &context()), "0.0 V");
+        assert_eq!(volts.format(0.0025, &context()), "2.5 mV");
+        assert_eq!(volts.format(-1500.0, &context()), "-1.5 kV");
+        assert_eq!(volts.format(3.2e6, &context()), "3.2 MV");
+        assert_eq!(volts.format(999.96, &context()), "1.0 kV");
+        assert_eq!(SiPrefix::new(0, "").format(42.0, &context()), "42");
+    }
+
+    #[test]
+    fn test_clock() {
+        let clock = Clock::new();
+        assert_eq!(clock.format(0.0, &context()), "00:00");
+        assert_eq!(clock.format(59.6, &context()), "01:00");
+        assert_eq!(clock.format(-75.0, &context()), "-01:15");
+        assert_eq!(clock.format(3725.0, &context()), "1:02:05");
+    }
+
+    #[test]
+    fn test_shared_formatter() {
+        let formatter = SharedFormatter::new(Unit::new(0, "°C"));
+        let context = FormatContext::new(-20.0, 40.0);
+        assert_eq!(formatter.clone().format(21.4, &context), "21°C");
+    }
+
+    #[test]
+    fn test_number_format_default_matches_format() {
+        let format = NumberFormat::new();
+        for value in [0.0, -0.04, 12.345, -1234567.891, 1e21] {
+            assert_eq!(format.format(value, 2), format!("{value:.2}"));
+        }
+        assert_eq!(format.format(f64::INFINITY, 1), "inf");
+    }
+
+    #[test]
+    fn test_number_format_grouping() {
+        let format = NumberFormat::new()
+            .decimal_separator(',')
+            .grouping_separator('.');
+        assert_eq!(format.format(999.0, 0), "999");
+        assert_eq!(format.format(1000.0, 0), "1.000");
+        assert_eq!(format.format(-1234567.25, 2), "-1.234.567,25");
+        assert_eq!(format.grouping_size(0).format(1234.0, 0), "1234");
+    }
+
+    #[test]
+    fn test_number_format_sign() {
+        let always = NumberFormat::new().sign(SignStyle::Always);
+        assert_eq!(always.format(0.0, 0), "+0");
+        assert_eq!(always.format(-2.0, 0), "-2");
+
+        let parentheses = NumberFormat::new().sign(SignStyle::Parentheses);
+        assert_eq!(parentheses.format(2.0, 0), "2");
+        assert_eq!(parentheses.format(-2.0, 0), "(2)");
+    }
+
+    #[test]
+    fn test_builtins_use_number_format() {
+        let context = FormatContext::new(0.0, 20_000.0).number_format(
+            NumberFormat::new()
+                .decimal_separator(',')
+                .grouping_separator(' '),
+        );
+        assert_eq!(Decimals::new(1).format(12345.5, &context), "12 345,5");
+        assert_eq!(Percent::new(1).format(2500.0, &context), "12,5%");
+        assert_eq!(Unit::new(1, " dB").format(-6.0, &context), "-6,0 dB");
+        assert_eq!(SiPrefix::new(1, "Hz").format(1200.0, &context), "1,2 kHz");
+    }
+
+    #[test]
+    fn test_format_editable() {
+        let comma = NumberFormat::new()
+            .decimal_separator(',')
+            .grouping_separator('.');
+        assert_eq!(comma.format_editable(1234.5, 1), "1234,5");
+        let other = NumberFormat::new().decimal_separator('·');
+        assert_eq!(other.format_editable(1234.5, 1), "1234.5");
+    }
+}
@@ -0,0 +1,410 @@
+//! Color gradient module
+//!
+//! This module provides color gradients that can be applied across the filled
+//! portion of a slider track.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::style::Color;
+//! use tui_slider::gradient::{Gradient, GradientSpan};
+//! use tui_slider::Slider;
+//!
+//! let gradient = Gradient::new([Color::Rgb(0, 200, 0), Color::Rgb(255, 0, 0)])
+//!     .span(GradientSpan::Fill);
+//!
+//! let slider = Slider::new(75.0, 0.0, 100.0).gradient(gradient);
+//! ```
+//!
+//! Colors are interpolated in RGB. On terminals without truecolor support the
+//! interpolated colors fall back to the nearest entry of the 256-color palette.
+
+use ratatui::style::Color;
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// RGB values of the 16 standard terminal colors, as used by xterm
+const STANDARD_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The part of the track a gradient is stretched over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientSpan {
+    /// The gradient covers the whole track, so a cell keeps its color as the value changes
+    #[default]
+    Track,
+    /// The gradient covers only the filled portion and ends at the handle
+    Fill,
+}
+
+/// Colors the terminal is able to display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSupport {
+    /// 24-bit RGB colors
+    #[default]
+    TrueColor,
+    /// The 256-color palette
+    Indexed,
+}
+
+impl ColorSupport {
+    /// Detects color support from the `COLORTERM` environment variable
+    ///
+    /// Terminals advertising `truecolor` or `24bit` get [`ColorSupport::TrueColor`],
+    /// everything else gets [`ColorSupport::Indexed`].
+    pub fn detect() -> Self {
+        match std::env::var("COLORTERM") {
+            Ok(value) if value.eq_ignore_ascii_case("truecolor") => Self::TrueColor,
+            Ok(value) if value.eq_ignore_ascii_case("24bit") => Self::TrueColor,
+            _ => Self::Indexed,
+        }
+    }
+}
+
+/// A color gradient made of color stops
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use tui_slider::gradient::{ColorSupport, Gradient};
+///
+/// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)])
+///     .color_support(ColorSupport::TrueColor);
+/// assert_eq!(gradient.color_at(0.5), Color::Rgb(128, 128, 128));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// Color stops sorted by position in `0.0..=1.0`
+    stops: Vec<(f64, Color)>,
+    /// The part of the track the gradient covers
+    span: GradientSpan,
+    /// Colors the terminal can display
+    color_support: ColorSupport,
+}
+
+impl Gradient {
+    /// Creates a gradient from evenly spaced colors
+    ///
+    /// Color support is detected with [`ColorSupport::detect`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::gradient::Gradient;
+    ///
+    /// let gradient = Gradient::new([Color::Green, Color::Yellow, Color::Red]);
+    /// ```
+    pub fn new(colors: impl IntoIterator<Item = Color>) -> Self {
+        let colors: Vec<Color> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        Self::from_stops(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f64 / last, color)),
+        )
+    }
+
+    /// Creates a gradient from color stops at positions in `0.0..=1.0`
+    ///
+    /// Positions are clamped to the range and sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::gradient::Gradient;
+    ///
+    /// // Stay green for most of the track, then turn red quickly
+    /// let gradient = Gradient::from_stops([
+    ///     (0.0, Color::Rgb(0, 200, 0)),
+    ///     (0.8, Color::Rgb(0, 200, 0)),
+    ///     (1.0, Color::Rgb(255, 0, 0)),
+    /// ]);
+    /// ```
+    pub fn from_stops(stops: impl IntoIterator<Item = (f64, Color)>) -> Self {
+        let mut stops: Vec<(f64, Color)> = stops
+            .into_iter()
+            .map(|(position, color)| (position.clamp(0.0, 1.0), color))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            stops,
+            span: GradientSpan::default(),
+            color_support: ColorSupport::detect(),
+        }
+    }
+
+    /// Sets the part of the track the gradient covers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::gradient::{Gradient, GradientSpan};
+    ///
+    /// let gradient = Gradient::new([Color::Blue, Color::Cyan]).span(GradientSpan::Fill);
+    /// ```
+    pub fn span(mut self, span: GradientSpan) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Overrides the detected color support
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::gradient::{ColorSupport, Gradient};
+    ///
+    /// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(255, 0, 0)])
+    ///     .color_support(ColorSupport::Indexed);
+    /// assert_eq!(gradient.color_at(1.0), Color::Indexed(196));
+    /// ```
+    pub fn color_support(mut self, support: ColorSupport) -> Self {
+        self.color_support = support;
+        self
+    }
+
+    /// Returns the color stops
+    pub fn stops(&self) -> &[(f64, Color)] {
+        &self.stops
+    }
+
+    /// Returns the color of a filled cell
+    ///
+    /// `cell` is the cell's index along the track, `cells` the track length and
+    /// `filled` the number of cells holding any fill.
+    pub(crate) fn cell_color(&self, cell: usize, cells: usize, filled: usize) -> Color {
+        let extent = match self.span {
+            GradientSpan::Track => cells,
+            GradientSpan::Fill => filled,
+        };
+        self.color_at(cell as f64 / extent.saturating_sub(1).max(1) as f64)
+    }
+
+    /// Returns the color at `position` in `0.0..=1.0`
+    ///
+    /// Neighbouring stops are interpolated in RGB when both can be expressed as
+    /// RGB; otherwise the nearer stop is used. The result is mapped to the
+    /// 256-color palette when color support is [`ColorSupport::Indexed`].
+    pub fn color_at(&self, position: f64) -> Color {
+        let position = position.clamp(0.0, 1.0);
+        let color = match self.stops.as_slice() {
+            [] => Color::Reset,
+            [(_, color)] => *color,
+            stops => {
+                let upper = stops
+                    .iter()
+                    .position(|(stop, _)| *stop >= position)
+                    .unwrap_or(stops.len() - 1)
+                    .max(1);
+                let (start, from) = stops[upper - 1];
+                let (end, to) = stops[upper];
+                let t = if end > start {
+                    ((position - start) / (end - start)).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                interpolate(from, to, t)
+            }
+        };
+        match self.color_support {
+            ColorSupport::TrueColor => color,
+            ColorSupport::Indexed => to_indexed(color),
+        }
+    }
+}
+
+/// Interpolates between two colors, falling back to the nearer one
+fn interpolate(from: Color, to: Color, t: f64) -> Color {
+    match (to_rgb(from), to_rgb(to)) {
+        (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// Returns the RGB value of a color, if it has a well-known one
+fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let standard = |index: usize| Some(STANDARD_COLORS[index]);
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Black => standard(0),
+        Color::Red => standard(1),
+        Color::Green => standard(2),
+        Color::Yellow => standard(3),
+        Color::Blue => standard(4),
+        Color::Magenta => standard(5),
+        Color::Cyan => standard(6),
+        Color::Gray => standard(7),
+        Color::DarkGray => standard(8),
+        Color::LightRed => standard(9),
+        Color::LightGreen => standard(10),
+        Color::LightYellow => standard(11),
+        Color::LightBlue => standard(12),
+        Color::LightMagenta => standard(13),
+        Color::LightCyan => standard(14),
+        Color::White => standard(15),
+        Color::Indexed(index) => Some(indexed_to_rgb(index)),
+        Color::Reset => None,
+    }
+}
+
+/// Returns the RGB value of an entry of the 256-color palette
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => STANDARD_COLORS[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Maps RGB colors to the nearest color cube or grayscale entry of the 256-color
+/// palette
+///
+/// Other colors are already displayable and are returned unchanged.
+fn to_indexed(color: Color) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+
+    let nearest_level = |channel: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (**level as i32 - channel as i32).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+    let cube = 16 + 36 * nearest_level(r) + 6 * nearest_level(g) + nearest_level(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let grey = 232 + ((average.saturating_sub(3)) / 10).min(23) as u8;
+
+    let distance = |index: u8| {
+        let (pr, pg, pb) = indexed_to_rgb(index);
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(pr, r) + d(pg, g) + d(pb, b)
+    };
+    if distance(grey) < distance(cube) {
+        Color::Indexed(grey)
+    } else {
+        Color::Indexed(cube)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn truecolor(colors: impl IntoIterator<Item = Color>) -> Gradient {
+        Gradient::new(colors).color_support(ColorSupport::TrueColor)
+    }
+
+    #[test]
+    fn test_evenly_spaced_stops() {
+        let gradient = truecolor([Color::Red, Color::Green, Color::Blue]);
+        let positions: Vec<f64> = gradient.stops().iter().map(|(p, _)| *p).collect();
+        assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_color_at() {
+        let gradient = truecolor([Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)]);
+        assert_eq!(gradient.color_at(0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.color_at(0.5), Color::Rgb(100, 50, 0));
+        assert_eq!(gradient.color_at(1.0), Color::Rgb(200, 100, 0));
+        assert_eq!(gradient.color_at(2.0), Color::Rgb(200, 100, 0));
+    }
+
+    #[test]
+    fn test_color_at_multiple_stops() {
+        let gradient = Gradient::from_stops([
+            (1.0, Color::Rgb(255, 0, 0)),
+            (0.0, Color::Rgb(0, 0, 0)),
+            (0.5, Color::Rgb(0, 0, 0)),
+        ])
+        .color_support(ColorSupport::TrueColor);
+        assert_eq!(gradient.color_at(0.25), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.color_at(0.75), Color::Rgb(128, 0, 0));
+    }
+
+    #[test]
+    fn test_color_at_without_rgb() {
+        let gradient = truecolor([Color::Reset, Color::Rgb(255, 0, 0)]);
+        assert_eq!(gradient.color_at(0.25), Color::Reset);
+        assert_eq!(gradient.color_at(0.75), Color::Rgb(255, 0, 0));
+
+        assert_eq!(truecolor([]).color_at(0.5), Color::Reset);
+        assert_eq!(truecolor([Color::Cyan]).color_at(0.5), Color::Cyan);
+    }
+
+    #[test]
+    fn test_cell_color_span() {
+        let track = truecolor([Color::Rgb(0, 0, 0), Color::Rgb(100, 0, 0)]);
+        assert_eq!(track.cell_color(2, 5, 3), Color::Rgb(50, 0, 0));
+
+        let fill = track.clone().span(GradientSpan::Fill);
+        assert_eq!(fill.cell_color(2, 5, 3), Color::Rgb(100, 0, 0));
+        assert_eq!(fill.cell_color(0, 5, 1), Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn test_indexed_fallback() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)])
+            .color_support(ColorSupport::Indexed);
+        assert_eq!(gradient.color_at(0.0), Color::Indexed(16));
+        assert_eq!(gradient.color_at(1.0), Color::Indexed(231));
+        // Mid grey is closer to the grayscale ramp than to the cube
+        assert_eq!(gradient.color_at(0.5), Color::Indexed(244));
+    }
+
+    #[test]
+    fn test_to_indexed() {
+        assert_eq!(to_indexed(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+        assert_eq!(to_indexed(Color::Rgb(0, 135, 255)), Color::Indexed(33));
+        assert_eq!(to_indexed(Color::Cyan), Color::Cyan);
+    }
+
+    #[test]
+    fn test_indexed_to_rgb() {
+        assert_eq!(indexed_to_rgb(1), (205, 0, 0));
+        assert_eq!(indexed_to_rgb(196), (255, 0, 0));
+        assert_eq!(indexed_to_rgb(232), (8, 8, 8));
+        assert_eq!(indexed_to_rgb(255), (238, 238, 238));
+    }
+}
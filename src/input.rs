@@ -0,0 +1,386 @@
+//! Backend-neutral input events
+//!
+//! This module defines [`SliderInput`], a small event type that
+//! [`SliderState::handle_input`](crate::SliderState::handle_input) understands,
+//! independent of the terminal backend used with ratatui.
+//!
+//! Conversions are provided behind cargo features:
+//!
+//! - `crossterm` (default) - `From<crossterm::event::Event>`, `KeyEvent` and `MouseEvent`
+//! - `termion` - `From<termion::event::Event>`, `Key` and `MouseEvent`
+//! - `termwiz` - `From<termwiz::input::InputEvent>`, `KeyEvent` and `MouseEvent`
+//!
+//! Mouse coordinates are always zero-based terminal cells, matching ratatui's
+//! [`Rect`](ratatui::layout::Rect) coordinates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tui_slider::input::{SliderInput, SliderKey};
+//! use tui_slider::SliderState;
+//!
+//! let mut state = SliderState::new(50.0, 0.0, 100.0);
+//!
+//! state.handle_input(SliderInput::Key(SliderKey::Right));
+//! assert_eq!(state.value(), 51.0);
+//! ```
+
+/// A keyboard or mouse event understood by the slider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliderInput {
+    /// A key press
+    Key(SliderKey),
+    /// A mouse action
+    Mouse(SliderMouse),
+    /// Any event the slider does not react to
+    Unsupported,
+}
+
+/// Keys the slider reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliderKey {
+    /// Left arrow
+    Left,
+    /// Right arrow
+    Right,
+    /// Up arrow
+    Up,
+    /// Down arrow
+    Down,
+    /// Home key
+    Home,
+    /// End key
+    End,
+    /// Page up
+    PageUp,
+    /// Page down
+    PageDown,
+    /// Enter / return
+    Enter,
+    /// Escape
+    Esc,
+    /// Backspace
+    Backspace,
+    /// Delete
+    Delete,
+    /// A printable character
+    Char(char),
+}
+
+/// A mouse action at a terminal cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliderMouse {
+    /// What happened
+    pub kind: SliderMouseKind,
+    /// Zero-based column
+    pub column: u16,
+    /// Zero-based row
+    pub row: u16,
+}
+
+impl SliderMouse {
+    /// Creates a new mouse event
+    pub fn new(kind: SliderMouseKind, column: u16, row: u16) -> Self {
+        Self { kind, column, row }
+    }
+}
+
+/// Kinds of mouse actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliderMouseKind {
+    /// Primary button pressed
+    Down,
+    /// Mouse moved with the primary button held
+    Drag,
+    /// Primary button released
+    Up,
+    /// Wheel scrolled up
+    ScrollUp,
+    /// Wheel scrolled down
+    ScrollDown,
+    /// Mouse moved without a button held, or another button was used
+    Moved,
+}
+
+impl From<SliderKey> for SliderInput {
+    fn from(key: SliderKey) -> Self {
+        SliderInput::Key(key)
+    }
+}
+
+impl From<SliderMouse> for SliderInput {
+    fn from(mouse: SliderMouse) -> Self {
+        SliderInput::Mouse(mouse)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+mod crossterm_input {
+    use super::{SliderInput, SliderKey, SliderMouse, SliderMouseKind};
+    use crossterm::event::{
+        Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+    };
+
+    impl From<Event> for SliderInput {
+        fn from(event: Event) -> Self {
+            match event {
+                Event::Key(key) => key.into(),
+                Event::Mouse(mouse) => mouse.into(),
+                _ => SliderInput::Unsupported,
+            }
+        }
+    }
+
+    impl From<KeyEvent> for SliderInput {
+        fn from(event: KeyEvent) -> Self {
+            // Windows also reports key releases; only presses and repeats move the slider
+            if event.kind == KeyEventKind::Release {
+                return SliderInput::Unsupported;
+            }
+
+            let key = match event.code {
+                KeyCode::Left => SliderKey::Left,
+                KeyCode::Right => SliderKey::Right,
+                KeyCode::Up => SliderKey::Up,
+                KeyCode::Down => SliderKey::Down,
+                KeyCode::Home => SliderKey::Home,
+                KeyCode::End => SliderKey::End,
+                KeyCode::PageUp => SliderKey::PageUp,
+                KeyCode::PageDown => SliderKey::PageDown,
+                KeyCode::Enter => SliderKey::Enter,
+                KeyCode::Esc => SliderKey::Esc,
+                KeyCode::Backspace => SliderKey::Backspace,
+                KeyCode::Delete => SliderKey::Delete,
+                KeyCode::Char(c) => SliderKey::Char(c),
+                _ => return SliderInput::Unsupported,
+            };
+            SliderInput::Key(key)
+        }
+    }
+
+    impl From<MouseEvent> for SliderInput {
+        fn from(event: MouseEvent) -> Self {
+            let kind = match event.kind {
+                MouseEventKind::Down(MouseButton::Left) => SliderMouseKind::Down,
+                MouseEventKind::Drag(MouseButton::Left) => SliderMouseKind::Drag,
+                MouseEventKind::Up(MouseButton::Left) => SliderMouseKind::Up,
+                MouseEventKind::ScrollUp => SliderMouseKind::ScrollUp,
+                MouseEventKind::ScrollDown => SliderMouseKind::ScrollDown,
+                _ => SliderMouseKind::Moved,
+            };
+            SliderInput::Mouse(SliderMouse::new(kind, event.column, event.row))
+        }
+    }
+}
+
+#[cfg(all(feature = "termion", not(windows)))]
+mod termion_input {
+    use super::{SliderInput, SliderKey, SliderMouse, SliderMouseKind};
+    use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+    impl From<Event> for SliderInput {
+        fn from(event: Event) -> Self {
+            match event {
+                Event::Key(key) => key.into(),
+                Event::Mouse(mouse) => mouse.into(),
+                Event::Unsupported(_) => SliderInput::Unsupported,
+            }
+        }
+    }
+
+    impl From<Key> for SliderInput {
+        fn from(key: Key) -> Self {
+            let key = match key {
+                Key::Left => SliderKey::Left,
+                Key::Right => SliderKey::Right,
+                Key::Up => SliderKey::Up,
+                Key::Down => SliderKey::Down,
+                Key::Home => SliderKey::Home,
+                Key::End => SliderKey::End,
+                Key::PageUp => SliderKey::PageUp,
+                Key::PageDown => SliderKey::PageDown,
+                Key::Char('\n') => SliderKey::Enter,
+                Key::Esc => SliderKey::Esc,
+                Key::Backspace => SliderKey::Backspace,
+                Key::Delete => SliderKey::Delete,
+                Key::Char(c) => SliderKey::Char(c),
+                _ => return SliderInput::Unsupported,
+            };
+            SliderInput::Key(key)
+        }
+    }
+
+    impl From<MouseEvent> for SliderInput {
+        fn from(event: MouseEvent) -> Self {
+            // termion reports one-based coordinates
+            let (kind, column, row) = match event {
+                MouseEvent::Press(MouseButton::Left, x, y) => (SliderMouseKind::Down, x, y),
+                MouseEvent::Press(MouseButton::WheelUp, x, y) => (SliderMouseKind::ScrollUp, x, y),
+                MouseEvent::Press(MouseButton::WheelDown, x, y) => {
+                    (SliderMouseKind::ScrollDown, x, y)
+                }
+                MouseEvent::Press(_, x, y) => (SliderMouseKind::Moved, x, y),
+                MouseEvent::Hold(x, y) => (SliderMouseKind::Drag, x, y),
+                MouseEvent::Release(x, y) => (SliderMouseKind::Up, x, y),
+            };
+            SliderInput::Mouse(SliderMouse::new(
+                kind,
+                column.saturating_sub(1),
+                row.saturating_sub(1),
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "termwiz")]
+mod termwiz_input {
+    use super::{SliderInput, SliderKey, SliderMouse, SliderMouseKind};
+    use termwiz::input::{InputEvent, KeyCode, KeyEvent, MouseButtons, MouseEvent};
+
+    impl From<InputEvent> for SliderInput {
+        fn from(event: InputEvent) -> Self {
+            match event {
+                InputEvent::Key(key) => key.into(),
+                InputEvent::Mouse(mouse) => mouse.into(),
+                _ => SliderInput::Unsupported,
+            }
+        }
+    }
+
+    impl From<KeyEvent> for SliderInput {
+        fn from(event: KeyEvent) -> Self {
+            let key = match event.key {
+                KeyCode::LeftArrow => SliderKey::Left,
+                KeyCode::RightArrow => SliderKey::Right,
+                KeyCode::UpArrow => SliderKey::Up,
+                KeyCode::DownArrow => SliderKey::Down,
+                KeyCode::Home => SliderKey::Home,
+                KeyCode::End => SliderKey::End,
+                KeyCode::PageUp => SliderKey::PageUp,
+                KeyCode::PageDown => SliderKey::PageDown,
+                KeyCode::Enter => SliderKey::Enter,
+                KeyCode::Escape => SliderKey::Esc,
+                KeyCode::Backspace => SliderKey::Backspace,
+                KeyCode::Delete => SliderKey::Delete,
+                KeyCode::Char(c) => SliderKey::Char(c),
+                _ => return SliderInput::Unsupported,
+            };
+            SliderInput::Key(key)
+        }
+    }
+
+    impl From<MouseEvent> for SliderInput {
+        fn from(event: MouseEvent) -> Self {
+            // termwiz reports the buttons currently held rather than press/release
+            // transitions, so a held primary button is reported as a press and a
+            // release as a move. The state treats presses during a drag as drags,
+            // so dragging still follows the pointer outside the track.
+            let buttons = event.mouse_buttons;
+            let kind = if buttons.contains(MouseButtons::VERT_WHEEL) {
+                if buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+                    SliderMouseKind::ScrollUp
+                } else {
+                    SliderMouseKind::ScrollDown
+                }
+            } else if buttons.contains(MouseButtons::LEFT) {
+                SliderMouseKind::Down
+            } else {
+                SliderMouseKind::Moved
+            };
+            // termwiz reports one-based coordinates
+            SliderInput::Mouse(SliderMouse::new(
+                kind,
+                event.x.saturating_sub(1),
+                event.y.saturating_sub(1),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_key_and_mouse() {
+        assert_eq!(
+            SliderInput::from(SliderKey::Home),
+            SliderInput::Key(SliderKey::Home)
+        );
+
+        let mouse = SliderMouse::new(SliderMouseKind::Down, 3, 4);
+        assert_eq!(SliderInput::from(mouse), SliderInput::Mouse(mouse));
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn test_crossterm_conversion() {
+        use crossterm::event::{
+            Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+            MouseEventKind,
+        };
+
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            SliderInput::from(Event::Key(key)),
+            SliderInput::Key(SliderKey::Enter)
+        );
+
+        let mut release = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        release.kind = KeyEventKind::Release;
+        assert_eq!(SliderInput::from(release), SliderInput::Unsupported);
+
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Left),
+            column: 7,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            SliderInput::from(mouse),
+            SliderInput::Mouse(SliderMouse::new(SliderMouseKind::Drag, 7, 2))
+        );
+        assert_eq!(
+            SliderInput::from(Event::FocusGained),
+            SliderInput::Unsupported
+        );
+    }
+
+    #[cfg(all(feature = "termion", not(windows)))]
+    #[test]
+    fn test_termion_conversion() {
+        use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+        assert_eq!(
+            SliderInput::from(Event::Key(Key::Char('\n'))),
+            SliderInput::Key(SliderKey::Enter)
+        );
+        assert_eq!(
+            SliderInput::from(MouseEvent::Press(MouseButton::Left, 1, 1)),
+            SliderInput::Mouse(SliderMouse::new(SliderMouseKind::Down, 0, 0))
+        );
+    }
+
+    #[cfg(feature = "termwiz")]
+    #[test]
+    fn test_termwiz_conversion() {
+        use termwiz::input::{KeyCode, KeyEvent, Modifiers, MouseButtons, MouseEvent};
+
+        let key = KeyEvent {
+            key: KeyCode::UpArrow,
+            modifiers: Modifiers::NONE,
+        };
+        assert_eq!(SliderInput::from(key), SliderInput::Key(SliderKey::Up));
+
+        let mouse = MouseEvent {
+            x: 5,
+            y: 3,
+            mouse_buttons: MouseButtons::VERT_WHEEL | MouseButtons::WHEEL_POSITIVE,
+            modifiers: Modifiers::NONE,
+        };
+        assert_eq!(
+            SliderInput::from(mouse),
+            SliderInput::Mouse(SliderMouse::new(SliderMouseKind::ScrollUp, 4, 2))
+        );
+    }
+}
@@ -8,7 +8,16 @@
 //!
 //! - **Horizontal and Vertical sliders** - Support for both orientations
 //! - **Simple styling** - Customizable colors and symbols
+//! - **Themes** - Shared palettes with focused and disabled looks
+//! - **Sub-cell precision** - Eighth-block fills for 8x the visual resolution
+//! - **Gradients** - RGB color gradients with 256-color fallback
+//! - **Zones** - Threshold colors such as green/yellow/red meters
+//! - **Ticks** - Major and minor tick marks with scale labels
+//! - **Markers** - Reference values such as a target or default drawn on the track
+//! - **Meters** - Peak-hold level meters with hold time and decay
+//! - **Value formatting** - Percentages, units, SI prefixes, durations and locale number formats
 //! - **State management** - Built-in state for value tracking
+//! - **Input handling** - Keyboard and mouse handling for crossterm, termion and termwiz
 //! - **Easy to use** - Minimal configuration required
 //!
 //! ## Quick Start
@@ -28,6 +37,10 @@
 //!
 //! // Render it (in your terminal UI loop)
 //! // frame.render_widget(slider, area);
+//!
+//! // Or render it straight from the state, which keeps value and step in sync
+//! // and records the layout for mouse handling
+//! // frame.render_stateful_widget(Slider::default(), area, &mut state);
 //! ```
 //!
 //! ## Customization
@@ -64,17 +77,29 @@
 //! ```
 
 pub mod border;
+pub mod fill;
+pub mod format;
+pub mod gradient;
+pub mod input;
+pub mod marker;
+pub mod meter;
 pub mod orientation;
 pub mod position;
 pub mod slider;
 pub mod state;
 pub mod style;
 pub mod symbols;
+pub mod theme;
+pub mod tick;
+pub mod zone;
 
 // Re-export main types
+pub use fill::FillMode;
+pub use input::SliderInput;
 pub use orientation::SliderOrientation;
 pub use position::{
-    HorizontalBarAlignment, VerticalLabelPosition, VerticalValueAlignment, VerticalValuePosition,
+    HorizontalBarAlignment, ValuePlacement, VerticalLabelPosition, VerticalValueAlignment,
+    VerticalValuePosition,
 };
 pub use slider::Slider;
 pub use state::SliderState;
@@ -82,14 +107,25 @@ pub use state::SliderState;
 /// Prelude module for convenient imports
 pub mod prelude {
     pub use crate::border;
+    pub use crate::fill::FillMode;
+    pub use crate::format::{
+        Clock, Decimals, NumberFormat, Percent, SiPrefix, SignStyle, Unit, ValueFormatter,
+    };
+    pub use crate::gradient::{Gradient, GradientSpan};
+    pub use crate::input::{SliderInput, SliderKey, SliderMouse, SliderMouseKind};
+    pub use crate::marker::Marker;
+    pub use crate::meter::MeterState;
     pub use crate::orientation::SliderOrientation;
     pub use crate::position::{
-        VerticalLabelPosition, VerticalValueAlignment, VerticalValuePosition,
+        ValuePlacement, VerticalLabelPosition, VerticalValueAlignment, VerticalValuePosition,
     };
     pub use crate::slider::Slider;
     pub use crate::state::SliderState;
     pub use crate::style;
     pub use crate::symbols;
+    pub use crate::theme::{SliderTheme, ThemeStyles};
+    pub use crate::tick::{TickPlacement, TickSpacing, Ticks};
+    pub use crate::zone::{Zone, ZoneMode};
 }
 
 #[cfg(test)]
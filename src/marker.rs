@@ -0,0 +1,103 @@
+//! Reference marker module
+//!
+//! This module provides markers for reference values such as a target setpoint,
+//! the last saved value or the factory default. Markers are drawn on the track
+//! alongside the handle without moving it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::style::Color;
+//! use tui_slider::marker::Marker;
+//! use tui_slider::Slider;
+//!
+//! let slider = Slider::new(40.0, 0.0, 100.0).markers([
+//!     Marker::new(70.0, "▼").color(Color::Green).label("target"),
+//!     Marker::new(50.0, "◆").color(Color::DarkGray),
+//! ]);
+//! ```
+
+use ratatui::style::Color;
+
+/// A reference value drawn on the track
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    /// Value the marker points at
+    value: f64,
+    /// Symbol drawn on the track
+    symbol: String,
+    /// Color of the symbol and label
+    color: Color,
+    /// Text drawn next to the track
+    label: Option<String>,
+}
+
+impl Marker {
+    /// Creates a marker at `value` drawn with `symbol`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::marker::Marker;
+    ///
+    /// let marker = Marker::new(75.0, "|");
+    /// assert_eq!(marker.value(), 75.0);
+    /// ```
+    pub fn new<S: Into<String>>(value: f64, symbol: S) -> Self {
+        Self {
+            value,
+            symbol: symbol.into(),
+            color: Color::Yellow,
+            label: None,
+        }
+    }
+
+    /// Sets the color of the symbol and label
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::marker::Marker;
+    ///
+    /// let marker = Marker::new(75.0, "|").color(Color::Green);
+    /// ```
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets a label drawn above a horizontal track or right of a vertical one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::marker::Marker;
+    ///
+    /// let marker = Marker::new(50.0, "◆").label("default");
+    /// ```
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the value the marker points at
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the symbol drawn on the track
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the color of the symbol and label
+    pub(crate) fn display_color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the label, if any
+    pub(crate) fn label_text(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
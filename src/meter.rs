@@ -0,0 +1,299 @@
+//! Peak-hold meter module
+//!
+//! This module provides [`MeterState`], a [`SliderState`] that also tracks the
+//! highest recent value. The peak is held for a while and then falls back at a
+//! fixed rate, the way audio level meters behave.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//! use tui_slider::meter::MeterState;
+//! use tui_slider::Slider;
+//!
+//! let mut meter = MeterState::new(0.0, 100.0)
+//!     .hold(Duration::from_millis(800))
+//!     .decay(40.0);
+//!
+//! // On every new sample
+//! meter.set_value(72.0);
+//!
+//! // Once per frame
+//! meter.tick(Instant::now());
+//! let slider = Slider::from_meter(&meter).show_handle(false);
+//! ```
+
+use crate::state::SliderState;
+use std::time::{Duration, Instant};
+
+/// Default time the peak stays put before it starts to fall
+const DEFAULT_HOLD: Duration = Duration::from_secs(1);
+
+/// Default fraction of the range the peak falls per second
+const DEFAULT_DECAY_FRACTION: f64 = 0.5;
+
+/// State of a level meter with a peak-hold indicator
+#[derive(Debug, Clone)]
+pub struct MeterState {
+    /// Current level
+    state: SliderState,
+    /// Highest recent level
+    peak: f64,
+    /// Time the peak stays put after it is raised
+    hold: Duration,
+    /// Hold time left before the peak starts to fall
+    hold_remaining: Duration,
+    /// Rate the peak falls at, in value units per second
+    decay: f64,
+    /// Time of the previous tick
+    last_tick: Option<Instant>,
+}
+
+impl MeterState {
+    /// Creates a meter at its minimum level
+    ///
+    /// The peak is held for one second and then falls by half the range per
+    /// second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if min >= max
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// let meter = MeterState::new(-60.0, 0.0);
+    /// assert_eq!(meter.value(), -60.0);
+    /// assert_eq!(meter.peak(), -60.0);
+    /// ```
+    pub fn new(min: f64, max: f64) -> Self {
+        let state = SliderState::new(min, min, max);
+        Self {
+            peak: min,
+            decay: (max - min) * DEFAULT_DECAY_FRACTION,
+            state,
+            hold: DEFAULT_HOLD,
+            hold_remaining: Duration::ZERO,
+            last_tick: None,
+        }
+    }
+
+    /// Sets how long the peak stays put after it is raised
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// let meter = MeterState::new(0.0, 100.0).hold(Duration::from_millis(500));
+    /// ```
+    pub fn hold(mut self, hold: Duration) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Sets how fast the peak falls once the hold time is over
+    ///
+    /// The rate is in value units per second; negative rates are treated as zero,
+    /// which keeps the peak until [`reset_peak`](Self::reset_peak).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// // Fall 20 dB per second
+    /// let meter = MeterState::new(-60.0, 0.0).decay(20.0);
+    /// ```
+    pub fn decay(mut self, rate: f64) -> Self {
+        self.decay = rate.max(0.0);
+        self
+    }
+
+    /// Gets the current level
+    pub fn value(&self) -> f64 {
+        self.state.value()
+    }
+
+    /// Sets the current level, raising the peak if it is exceeded
+    ///
+    /// Raising the peak restarts the hold time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// let mut meter = MeterState::new(0.0, 100.0);
+    /// meter.set_value(80.0);
+    /// meter.set_value(30.0);
+    /// assert_eq!(meter.value(), 30.0);
+    /// assert_eq!(meter.peak(), 80.0);
+    /// ```
+    pub fn set_value(&mut self, value: f64) {
+        self.state.set_value(value);
+        let value = self.state.value();
+        if value >= self.peak {
+            self.peak = value;
+            self.hold_remaining = self.hold;
+        }
+    }
+
+    /// Gets the peak level
+    pub fn peak(&self) -> f64 {
+        self.peak
+    }
+
+    /// Drops the peak to the current level
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// let mut meter = MeterState::new(0.0, 100.0);
+    /// meter.set_value(80.0);
+    /// meter.set_value(30.0);
+    /// meter.reset_peak();
+    /// assert_eq!(meter.peak(), 30.0);
+    /// ```
+    pub fn reset_peak(&mut self) {
+        self.peak = self.state.value();
+        self.hold_remaining = Duration::ZERO;
+    }
+
+    /// Advances the peak hold and decay to `now`
+    ///
+    /// Call this once per frame. The first call only records the time. The peak
+    /// never falls below the current level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tui_slider::meter::MeterState;
+    ///
+    /// let mut meter = MeterState::new(0.0, 100.0)
+    ///     .hold(Duration::from_secs(1))
+    ///     .decay(10.0);
+    /// let start = Instant::now();
+    /// meter.tick(start);
+    ///
+    /// meter.set_value(90.0);
+    /// meter.set_value(0.0);
+    ///
+    /// // Held for one second, then two seconds of decay
+    /// meter.tick(start + Duration::from_secs(3));
+    /// assert_eq!(meter.peak(), 70.0);
+    /// ```
+    pub fn tick(&mut self, now: Instant) {
+        let elapsed = match self.last_tick {
+            Some(last) => now.saturating_duration_since(last),
+            None => Duration::ZERO,
+        };
+        self.last_tick = Some(now);
+
+        let held = elapsed.min(self.hold_remaining);
+        self.hold_remaining -= held;
+        let falling = elapsed - held;
+
+        let floor = self.state.value();
+        self.peak = (self.peak - self.decay * falling.as_secs_f64()).max(floor);
+    }
+
+    /// Returns the underlying slider state
+    pub fn state(&self) -> &SliderState {
+        &self.state
+    }
+
+    /// Returns the underlying slider state mutably
+    ///
+    /// Changing the value through the state does not update the peak.
+    pub fn state_mut(&mut self) -> &mut SliderState {
+        &mut self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meter() -> (MeterState, Instant) {
+        let mut meter = MeterState::new(0.0, 100.0)
+            .hold(Duration::from_millis(500))
+            .decay(20.0);
+        let start = Instant::now();
+        meter.tick(start);
+        (meter, start)
+    }
+
+    #[test]
+    fn test_new_defaults() {
+        let meter = MeterState::new(0.0, 100.0);
+        assert_eq!(meter.value(), 0.0);
+        assert_eq!(meter.peak(), 0.0);
+        assert_eq!(meter.decay, 50.0);
+        assert_eq!(meter.hold, DEFAULT_HOLD);
+    }
+
+    #[test]
+    fn test_peak_follows_rising_values() {
+        let (mut meter, _) = meter();
+        meter.set_value(40.0);
+        meter.set_value(60.0);
+        assert_eq!(meter.peak(), 60.0);
+        meter.set_value(20.0);
+        assert_eq!(meter.peak(), 60.0);
+
+        // Clamped like the state
+        meter.set_value(150.0);
+        assert_eq!(meter.peak(), 100.0);
+    }
+
+    #[test]
+    fn test_peak_holds_then_decays() {
+        let (mut meter, start) = meter();
+        meter.set_value(80.0);
+        meter.set_value(0.0);
+
+        meter.tick(start + Duration::from_millis(400));
+        assert_eq!(meter.peak(), 80.0);
+
+        // 100ms of hold left, then 500ms at 20 per second
+        meter.tick(start + Duration::from_millis(1000));
+        assert!((meter.peak() - 70.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_peak_never_falls_below_value() {
+        let (mut meter, start) = meter();
+        meter.set_value(80.0);
+        meter.set_value(75.0);
+        meter.tick(start + Duration::from_secs(10));
+        assert_eq!(meter.peak(), 75.0);
+    }
+
+    #[test]
+    fn test_raising_peak_restarts_hold() {
+        let (mut meter, start) = meter();
+        meter.set_value(50.0);
+        meter.tick(start + Duration::from_secs(2));
+        meter.set_value(60.0);
+        meter.set_value(0.0);
+        meter.tick(start + Duration::from_millis(2400));
+        assert_eq!(meter.peak(), 60.0);
+    }
+
+    #[test]
+    fn test_zero_decay_keeps_peak() {
+        let (meter, start) = meter();
+        let mut meter = meter.decay(-5.0);
+        meter.set_value(90.0);
+        meter.set_value(10.0);
+        meter.tick(start + Duration::from_secs(60));
+        assert_eq!(meter.peak(), 90.0);
+    }
+}
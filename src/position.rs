@@ -46,6 +46,17 @@ pub enum HorizontalBarAlignment {
     Bottom,
 }
 
+/// Where the value display is placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValuePlacement {
+    /// Value at the fixed position set by the alignment and position options
+    #[default]
+    Fixed,
+    /// Value directly above the handle (beside it for vertical sliders), moving
+    /// with it and clamped at the edges
+    FollowHandle,
+}
+
 impl VerticalValueAlignment {
     /// Convert to ratatui's Alignment
     pub fn to_ratatui_alignment(&self) -> ratatui::layout::Alignment {
@@ -100,6 +111,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_value_placement_default() {
+        assert_eq!(ValuePlacement::default(), ValuePlacement::Fixed);
+    }
+
     #[test]
     fn test_horizontal_bar_alignment_default() {
         assert_eq!(
@@ -11,28 +11,69 @@
 //! # Features
 //!
 //! - Horizontal and vertical orientations
+//! - Borders in every [`BorderStyle`](crate::border::BorderStyle), including segmented and sides-only ones
 //! - Customizable colors for filled, empty, and handle
+//! - Full [`Style`] for label, value, track and handle
 //! - Customizable symbols for bar and handle
-//! - Optional label and value display
+//! - [`SliderStyle`] presets applied in one call
+//! - [`SliderTheme`] palettes with normal, focused and disabled looks
+//! - Segmented bars with configurable segment length and gap
+//! - Thick tracks spanning several rows or columns
+//! - Sub-cell precision fills with eighth blocks or braille dots
+//! - Color gradients across the filled track
+//! - Threshold zones that color the fill by value range
+//! - Tick marks and scale labels along the track
+//! - Reference markers such as a target or default value
+//! - Peak-hold indicator for level meters
+//! - Secondary fill for buffered or downloaded progress
+//! - Optional label and value display, with the value optionally following the handle
+//! - Label and value rows reserved inside the widget area, or drawn outside it in the legacy layout
+//! - Pluggable value formatting for units, percentages and durations
 //! - Optional handle/thumb display
+//! - Inline edit field for typing exact values
 //! - State management with bounds checking
+//! - Size measurement with [`Slider::min_size`] and [`Slider::preferred_size`]
+//! - [`StatefulWidget`] rendering straight from [`SliderState`]
 
 use crate::{
+    border::{expand_title_template, SliderBlock},
+    fill::{braille_cell, FillMode},
+    format::{FormatContext, NumberFormat, Percent, SharedFormatter, ValueFormatter},
+    gradient::Gradient,
+    marker::Marker,
+    meter::MeterState,
     orientation::SliderOrientation,
     position::{
-        HorizontalBarAlignment, VerticalLabelPosition, VerticalValueAlignment,
+        HorizontalBarAlignment, ValuePlacement, VerticalLabelPosition, VerticalValueAlignment,
         VerticalValuePosition,
     },
-    state::SliderState,
+    state::{SliderLayout, SliderState, ValueEditor},
+    style::SliderStyle,
+    symbols::{
+        FILLED_BLOCK, HANDLE_HORIZONTAL_LINE, HANDLE_VERTICAL_LINE, HORIZONTAL_EIGHTHS,
+        LEFT_HALF_BLOCK, LOWER_HALF_BLOCK, RIGHT_HALF_BLOCK, UPPER_HALF_BLOCK, VERTICAL_EIGHTHS,
+    },
+    theme::{SliderTheme, ThemeStyles},
+    tick::{place_labels, tick_cell, TickPlacement, Ticks},
+    zone::{zone_at, Zone, ZoneMode},
 };
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
-    style::{Color, Style},
-    widgets::{Block, Widget},
+    layout::{Alignment, Position, Rect, Size},
+    style::{Color, Modifier, Style},
+    widgets::{Block, StatefulWidget, Widget},
 };
 use unicode_width::UnicodeWidthStr;
 
+/// Track length [`Slider::preferred_size`] asks for in a horizontal slider
+const PREFERRED_HORIZONTAL_LENGTH: u16 = 20;
+
+/// Track length [`Slider::preferred_size`] asks for in a vertical slider
+const PREFERRED_VERTICAL_LENGTH: u16 = 10;
+
+/// Most values formatted when measuring the widest value text
+const MAX_WIDTH_SAMPLES: usize = 1000;
+
 /// A simple slider widget for ratatui
 ///
 /// This widget can be used to display and control values in a terminal UI.
@@ -88,10 +129,32 @@ use unicode_width::UnicodeWidthStr;
 ///     .handle_color(Color::White)
 ///     .show_handle(true);
 /// ```
+///
+/// ## Rendering From State
+///
+/// As a [`StatefulWidget`] the slider reads the live value, bounds, step and
+/// edit field from [`SliderState`] at render time, and records where the track
+/// and handle were drawn so mouse events can be hit-tested.
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+/// use tui_slider::{Slider, SliderState};
+///
+/// let mut state = SliderState::new(50.0, 0.0, 100.0);
+/// let area = Rect::new(0, 0, 20, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// Slider::default().render(area, &mut buf, &mut state);
+/// assert_eq!(state.layout().unwrap().track, area);
+/// ```
 #[derive(Debug, Clone)]
 pub struct Slider<'a> {
     /// Optional block for borders
     block: Option<Block<'a>>,
+    /// Optional border drawn in one of the crate's border styles
+    border: Option<SliderBlock<'a>>,
+    /// Template for the border title, filled in with the current value
+    border_title: Option<String>,
     /// Slider orientation
     orientation: SliderOrientation,
     /// Current value
@@ -100,26 +163,73 @@ pub struct Slider<'a> {
     min: f64,
     /// Maximum value
     max: f64,
+    /// Step size between values
+    step: f64,
     /// Optional label
     label: Option<String>,
     /// Whether to show the value
     show_value: bool,
     /// Alignment of the value display
     value_alignment: Alignment,
+    /// Whether the value sits at a fixed position or follows the handle
+    value_placement: ValuePlacement,
     /// Filled bar symbol
     filled_symbol: String,
     /// Empty bar symbol
     empty_symbol: String,
     /// Handle symbol
     handle_symbol: String,
-    /// Filled bar color
-    filled_color: Color,
-    /// Empty bar color
-    empty_color: Color,
-    /// Handle color
-    handle_color: Color,
+    /// Style of the filled portion of the bar
+    filled_style: Style,
+    /// Style of the empty portion of the bar
+    empty_style: Style,
+    /// Style of the handle
+    handle_style: Style,
+    /// Style of the label
+    label_style: Style,
+    /// Style of the value display
+    value_style: Style,
+    /// Theme providing the focused and disabled looks
+    theme: Option<SliderTheme>,
+    /// Styles set by builder calls after the theme, kept over its focused and
+    /// disabled looks
+    style_overrides: ThemeStyles,
+    /// Whether the slider is drawn with the theme's focused look
+    focused: bool,
+    /// Whether the slider is drawn with the theme's disabled look
+    disabled: bool,
     /// Whether to show handle
     show_handle: bool,
+    /// Whether to render the bar as discrete segments
+    segmented: bool,
+    /// Number of symbols per segment
+    segment_length: u16,
+    /// Number of blank cells between segments
+    segment_gap: u16,
+    /// How the filled portion of the track is drawn
+    fill_mode: FillMode,
+    /// Color gradient for the filled portion, replacing the filled color
+    gradient: Option<Gradient>,
+    /// Value ranges with their own fill color and symbol
+    zones: Vec<Zone>,
+    /// Whether zones apply per cell or to the whole fill
+    zone_mode: ZoneMode,
+    /// Tick marks and scale labels drawn next to the track
+    ticks: Option<Ticks>,
+    /// Reference markers drawn on the track
+    markers: Vec<Marker>,
+    /// Peak level drawn as an indicator over the fill
+    peak: Option<f64>,
+    /// Symbol of the peak indicator, defaulting to a line across the track
+    peak_symbol: Option<String>,
+    /// Color of the peak indicator
+    peak_color: Color,
+    /// Secondary value drawn between the fill and the empty track
+    secondary_value: Option<f64>,
+    /// Symbol of the secondary fill, defaulting to the filled symbol
+    secondary_symbol: Option<String>,
+    /// Color of the secondary fill
+    secondary_color: Color,
     /// Label position for vertical sliders
     vertical_label_position: VerticalLabelPosition,
     /// Value position for vertical sliders
@@ -128,6 +238,16 @@ pub struct Slider<'a> {
     vertical_value_alignment: VerticalValueAlignment,
     /// Vertical alignment of the bar in horizontal sliders
     horizontal_bar_alignment: HorizontalBarAlignment,
+    /// Rows a horizontal track spans, or columns a vertical track spans
+    thickness: u16,
+    /// Draw the label and value outside the area, as older versions did
+    legacy_layout: bool,
+    /// Edit field shown in place of the value while typing
+    editor: Option<ValueEditor>,
+    /// Formatter for the value display
+    value_formatter: Option<SharedFormatter>,
+    /// How numbers are written, falling back to the state's when rendered from it
+    number_format: Option<NumberFormat>,
 }
 
 impl<'a> Slider<'a> {
@@ -156,24 +276,54 @@ impl<'a> Slider<'a> {
     pub fn new(value: f64, min: f64, max: f64) -> Self {
         Self {
             block: None,
+            border: None,
+            border_title: None,
             orientation: SliderOrientation::Horizontal,
             value: value.clamp(min, max),
             min,
             max,
+            step: 1.0,
             label: None,
             show_value: false,
             value_alignment: Alignment::Right,
+            value_placement: ValuePlacement::Fixed,
             filled_symbol: "━".to_string(),
             empty_symbol: "─".to_string(),
             handle_symbol: "●".to_string(),
-            filled_color: Color::Cyan,
-            empty_color: Color::DarkGray,
-            handle_color: Color::White,
+            filled_style: Style::new().fg(Color::Cyan),
+            empty_style: Style::new().fg(Color::DarkGray),
+            handle_style: Style::new().fg(Color::White),
+            label_style: Style::new(),
+            value_style: Style::new(),
+            theme: None,
+            style_overrides: ThemeStyles::new(),
+            focused: false,
+            disabled: false,
             show_handle: true,
+            segmented: false,
+            segment_length: 2,
+            segment_gap: 1,
+            fill_mode: FillMode::Symbols,
+            gradient: None,
+            zones: Vec::new(),
+            zone_mode: ZoneMode::PerCell,
+            ticks: None,
+            markers: Vec::new(),
+            peak: None,
+            peak_symbol: None,
+            peak_color: Color::Red,
+            secondary_value: None,
+            secondary_symbol: None,
+            secondary_color: Color::Gray,
             vertical_label_position: VerticalLabelPosition::default(),
             vertical_value_position: VerticalValuePosition::default(),
             vertical_value_alignment: VerticalValueAlignment::default(),
             horizontal_bar_alignment: HorizontalBarAlignment::default(),
+            thickness: 1,
+            legacy_layout: false,
+            editor: None,
+            value_formatter: None,
+            number_format: None,
         }
     }
 
@@ -181,6 +331,11 @@ impl<'a> Slider<'a> {
     ///
     /// This is the preferred way to create a slider as it works seamlessly with
     /// [`SliderState`] for managing value changes and keeping UI in sync.
+    /// While the state is in edit mode, the value display is replaced by the
+    /// edit field with a visible cursor.
+    ///
+    /// The state is copied when the slider is built; render the slider as a
+    /// [`StatefulWidget`] instead to read it at render time.
     ///
     /// # Examples
     ///
@@ -197,7 +352,33 @@ impl<'a> Slider<'a> {
     ///     .show_value(true);
     /// ```
     pub fn from_state(state: &SliderState) -> Self {
-        Self::new(state.value(), state.min(), state.max())
+        let mut slider = Self::new(state.value(), state.min(), state.max());
+        slider.step = state.step();
+        slider.editor = state.editor().cloned();
+        slider.value_formatter = state.value_formatter().cloned();
+        slider.number_format = Some(state.number_format());
+        slider
+    }
+
+    /// Creates a level meter slider from a meter state
+    ///
+    /// The slider shows the meter's level and its peak indicator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::meter::MeterState;
+    /// use tui_slider::{Slider, SliderOrientation};
+    ///
+    /// let mut meter = MeterState::new(-60.0, 0.0);
+    /// meter.set_value(-12.0);
+    ///
+    /// let slider = Slider::from_meter(&meter)
+    ///     .orientation(SliderOrientation::Vertical)
+    ///     .show_handle(false);
+    /// ```
+    pub fn from_meter(meter: &MeterState) -> Self {
+        Self::from_state(meter.state()).peak(meter.peak())
     }
 
     /// Sets the block for borders
@@ -216,6 +397,57 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Sets a border drawn in one of the crate's border styles
+    ///
+    /// Unlike [`block`](Self::block), this supports segmented and sides-only
+    /// styles. Accepts a [`SliderBlock`] or a bare
+    /// [`BorderStyle`](crate::border::BorderStyle). When both are
+    /// set, the border is drawn inside the block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::border::{BorderStyle, SliderBlock};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default().border(BorderStyle::DoubleSidesOnly);
+    ///
+    /// let slider = Slider::default().border(
+    ///     SliderBlock::new(BorderStyle::PlainSegmented)
+    ///         .title(" Volume ")
+    ///         .style(Style::new().fg(Color::Cyan)),
+    /// );
+    /// ```
+    pub fn border<B: Into<SliderBlock<'a>>>(mut self, border: B) -> Self {
+        self.border = Some(border.into());
+        self
+    }
+
+    /// Sets a border title template that follows the value
+    ///
+    /// `{label}`, `{value}` and `{percent}` are replaced on every render, with
+    /// `{value}` going through the [value formatter](Self::value_formatter). The
+    /// title replaces the one on the [`border`](Self::border) and is placed by
+    /// its alignment and position, measured at its actual width. Without a
+    /// border the template is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::border::{BorderStyle, SliderBlock, TitleAlignment};
+    /// use tui_slider::Slider;
+    ///
+    /// // Draws "╭ Volume — 72% ──╮"
+    /// let slider = Slider::new(72.0, 0.0, 100.0)
+    ///     .border(SliderBlock::new(BorderStyle::Rounded).title_alignment(TitleAlignment::Left))
+    ///     .border_title(" Volume — {percent} ");
+    /// ```
+    pub fn border_title<S: Into<String>>(mut self, template: S) -> Self {
+        self.border_title = Some(template.into());
+        self
+    }
+
     /// Sets the orientation (horizontal or vertical)
     ///
     /// # Examples
@@ -275,6 +507,20 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Sets the step size between values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default().step(5.0);
+    /// ```
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
     /// Sets the label text displayed above the slider
     ///
     /// # Examples
@@ -320,6 +566,75 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Sets the formatter for the value display
+    ///
+    /// Accepts a closure or any [`ValueFormatter`], such as the built-ins in
+    /// [`format`](crate::format). By default horizontal sliders show one decimal
+    /// and vertical sliders none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::format::SiPrefix;
+    /// use tui_slider::Slider;
+    ///
+    /// // "1.2 kHz"
+    /// let slider = Slider::new(1200.0, 20.0, 20_000.0)
+    ///     .show_value(true)
+    ///     .value_formatter(SiPrefix::new(1, "Hz"));
+    ///
+    /// let slider = Slider::new(-6.0, -60.0, 0.0)
+    ///     .show_value(true)
+    ///     .value_formatter(|value: f64| format!("{value:+.1} dB"));
+    /// ```
+    pub fn value_formatter<F: ValueFormatter + 'static>(mut self, formatter: F) -> Self {
+        self.value_formatter = Some(SharedFormatter::new(formatter));
+        self
+    }
+
+    /// Sets how numbers are written in the value display and tick labels
+    ///
+    /// Applies to the default value display, the built-in formatters and tick
+    /// labels. When rendered from a [`SliderState`] without a number format set
+    /// here, the state's number format is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::format::NumberFormat;
+    /// use tui_slider::Slider;
+    ///
+    /// // "12,5"
+    /// let slider = Slider::new(12.5, 0.0, 100.0)
+    ///     .show_value(true)
+    ///     .number_format(NumberFormat::new().decimal_separator(','));
+    /// ```
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    /// Sets where the value display is placed
+    ///
+    /// With [`ValuePlacement::FollowHandle`] the value is drawn directly above the
+    /// handle of a horizontal slider, or beside the handle of a vertical one, and
+    /// the alignment and position options are ignored. It is clamped to the area
+    /// and kept clear of the label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{Slider, ValuePlacement};
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0)
+    ///     .show_value(true)
+    ///     .value_placement(ValuePlacement::FollowHandle);
+    /// ```
+    pub fn value_placement(mut self, placement: ValuePlacement) -> Self {
+        self.value_placement = placement;
+        self
+    }
+
     /// Sets the symbol used for the filled portion of the bar
     ///
     /// # Examples
@@ -364,6 +679,8 @@ impl<'a> Slider<'a> {
 
     /// Sets the color of the filled portion of the bar
     ///
+    /// Only the foreground of the filled style is changed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -373,12 +690,15 @@ impl<'a> Slider<'a> {
     /// let slider = Slider::default().filled_color(Color::Cyan);
     /// ```
     pub fn filled_color(mut self, color: Color) -> Self {
-        self.filled_color = color;
+        self.filled_style = self.filled_style.fg(color);
+        self.style_overrides.filled = self.style_overrides.filled.fg(color);
         self
     }
 
     /// Sets the color of the empty portion of the bar
     ///
+    /// Only the foreground of the empty style is changed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -388,12 +708,15 @@ impl<'a> Slider<'a> {
     /// let slider = Slider::default().empty_color(Color::DarkGray);
     /// ```
     pub fn empty_color(mut self, color: Color) -> Self {
-        self.empty_color = color;
+        self.empty_style = self.empty_style.fg(color);
+        self.style_overrides.empty = self.style_overrides.empty.fg(color);
         self
     }
 
     /// Sets the color of the slider handle
     ///
+    /// Only the foreground of the handle style is changed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -403,7 +726,102 @@ impl<'a> Slider<'a> {
     /// let slider = Slider::default().handle_color(Color::White);
     /// ```
     pub fn handle_color(mut self, color: Color) -> Self {
-        self.handle_color = color;
+        self.handle_style = self.handle_style.fg(color);
+        self.style_overrides.handle = self.style_overrides.handle.fg(color);
+        self
+    }
+
+    /// Sets the style of the filled portion of the bar
+    ///
+    /// Zones and gradients replace the foreground color but keep the rest of the
+    /// style. Unset fields, such as the background, are left as they are in the
+    /// buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Modifier, Style};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default()
+    ///     .filled_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+    /// ```
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self.style_overrides.filled = style;
+        self
+    }
+
+    /// Sets the style of the empty portion of the bar
+    ///
+    /// The secondary fill uses this style with its own color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Modifier, Style};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default()
+    ///     .empty_style(Style::new().fg(Color::Gray).add_modifier(Modifier::DIM));
+    /// ```
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self.style_overrides.empty = style;
+        self
+    }
+
+    /// Sets the style of the slider handle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default().handle_style(Style::new().fg(Color::Black).bg(Color::White));
+    /// ```
+    pub fn handle_style(mut self, style: Style) -> Self {
+        self.handle_style = style;
+        self.style_overrides.handle = style;
+        self
+    }
+
+    /// Sets the style of the label
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default()
+    ///     .label("Volume")
+    ///     .label_style(Style::new().add_modifier(Modifier::BOLD));
+    /// ```
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self.style_overrides.label = style;
+        self
+    }
+
+    /// Sets the style of the value display
+    ///
+    /// While editing, the cursor cell is drawn with this style reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::default()
+    ///     .show_value(true)
+    ///     .value_style(Style::new().fg(Color::Yellow));
+    /// ```
+    pub fn value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self.style_overrides.value = style;
         self
     }
 
@@ -448,122 +866,758 @@ impl<'a> Slider<'a> {
         self.show_handle(show)
     }
 
-    /// Sets the label position for vertical sliders
+    /// Sets whether to render the bar as discrete segments separated by gaps
     ///
-    /// For vertical sliders, the label can be positioned at the top or bottom.
-    /// This setting only affects vertical sliders; horizontal sliders ignore this.
+    /// Segments are filled as whole units and the handle is placed on the
+    /// boundary after the last filled segment. The default pattern is two
+    /// symbols on, one cell off, like
+    /// [`create_segmented_line`](crate::border::create_segmented_line).
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_slider::{Slider, SliderOrientation, VerticalLabelPosition};
+    /// use tui_slider::Slider;
     ///
-    /// let slider = Slider::default()
-    ///     .orientation(SliderOrientation::Vertical)
-    ///     .label("Volume")
-    ///     .vertical_label_position(VerticalLabelPosition::Bottom);
+    /// let slider = Slider::default().segmented(true);
     /// ```
-    pub fn vertical_label_position(mut self, position: VerticalLabelPosition) -> Self {
-        self.vertical_label_position = position;
+    pub fn segmented(mut self, segmented: bool) -> Self {
+        self.segmented = segmented;
         self
     }
 
-    /// Sets the value position for vertical sliders
+    /// Sets the number of symbols in each segment of a segmented bar
     ///
-    /// For vertical sliders, the numeric value can be positioned at the top, middle, or bottom.
-    /// This setting only affects vertical sliders; horizontal sliders ignore this.
+    /// Values below 1 are treated as 1.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_slider::{Slider, SliderOrientation, VerticalValuePosition};
+    /// use tui_slider::Slider;
     ///
-    /// let slider = Slider::default()
-    ///     .orientation(SliderOrientation::Vertical)
-    ///     .show_value(true)
-    ///     .vertical_value_position(VerticalValuePosition::Top);
+    /// let slider = Slider::default().segmented(true).segment_length(3);
     /// ```
-    pub fn vertical_value_position(mut self, position: VerticalValuePosition) -> Self {
-        self.vertical_value_position = position;
+    pub fn segment_length(mut self, length: u16) -> Self {
+        self.segment_length = length.max(1);
         self
     }
 
-    /// Sets the value alignment for vertical sliders
+    /// Sets how the filled portion of the track is drawn
     ///
-    /// For vertical sliders, the numeric value can be aligned left, center, or right.
-    /// This setting only affects vertical sliders; horizontal sliders use `value_alignment`.
+    /// [`FillMode::Eighths`] draws full blocks with a partial block at the boundary
+    /// cell, giving eight levels per cell in both orientations. The filled symbol is
+    /// replaced by blocks while the empty symbol is kept. The handle is drawn over
+    /// the boundary cell, so combine with `show_handle(false)` for a smooth bar.
+    ///
+    /// [`FillMode::Braille`] draws the whole track in braille dots, filled one dot
+    /// line at a time, and replaces the handle symbol with a single line of dots in
+    /// the handle color.
+    ///
+    /// Segmented bars ignore the fill mode.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_slider::{Slider, SliderOrientation, VerticalValueAlignment};
+    /// use tui_slider::{FillMode, Slider};
     ///
-    /// let slider = Slider::default()
-    ///     .orientation(SliderOrientation::Vertical)
-    ///     .show_value(true)
-    ///     .vertical_value_alignment(VerticalValueAlignment::Left);
+    /// let slider = Slider::new(33.3, 0.0, 100.0)
+    ///     .fill_mode(FillMode::Eighths)
+    ///     .show_handle(false);
+    ///
+    /// // One-cell-tall dashboard gauge with a thin handle
+    /// let gauge = Slider::new(33.3, 0.0, 100.0).fill_mode(FillMode::Braille);
     /// ```
-    pub fn vertical_value_alignment(mut self, alignment: VerticalValueAlignment) -> Self {
-        self.vertical_value_alignment = alignment;
+    pub fn fill_mode(mut self, mode: FillMode) -> Self {
+        self.fill_mode = mode;
         self
     }
 
-    /// Sets the vertical alignment of the bar in horizontal sliders
+    /// Sets a color gradient for the filled portion of the track
     ///
-    /// For horizontal sliders, the bar can be positioned at the top, center (middle),
-    /// or bottom of the available area. This setting only affects horizontal sliders.
+    /// Each filled cell takes its color from the gradient instead of the filled
+    /// color. See [`Gradient`] for stops, span and color fallback.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_slider::{Slider, SliderOrientation, HorizontalBarAlignment};
+    /// use ratatui::style::Color;
+    /// use tui_slider::gradient::{Gradient, GradientSpan};
+    /// use tui_slider::Slider;
     ///
-    /// let slider = Slider::default()
-    ///     .orientation(SliderOrientation::Horizontal)
-    ///     .horizontal_bar_alignment(HorizontalBarAlignment::Top);
+    /// let slider = Slider::new(80.0, 0.0, 100.0).gradient(
+    ///     Gradient::new([Color::Rgb(0, 200, 0), Color::Rgb(255, 200, 0), Color::Rgb(255, 0, 0)])
+    ///         .span(GradientSpan::Track),
+    /// );
     /// ```
-    pub fn horizontal_bar_alignment(mut self, alignment: HorizontalBarAlignment) -> Self {
-        self.horizontal_bar_alignment = alignment;
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
         self
     }
 
-    /// Calculates the percentage (0.0 to 1.0) of the current value
-    fn percentage(&self) -> f64 {
-        if (self.max - self.min).abs() < f64::EPSILON {
-            return 0.0;
-        }
-        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    /// Sets the zones that color the filled track by value range
+    ///
+    /// Filled cells inside a zone take its color, and its symbol if one is set,
+    /// over the gradient and filled color. Where zones overlap the later one wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::Zone;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(90.0, 0.0, 100.0).zones([
+    ///     Zone::new(0.0, 80.0, Color::Green),
+    ///     Zone::new(80.0, 100.0, Color::Red),
+    /// ]);
+    /// ```
+    pub fn zones(mut self, zones: impl IntoIterator<Item = Zone>) -> Self {
+        self.zones = zones.into_iter().collect();
+        self
     }
 
-    /// Renders a horizontal slider
+    /// Adds a single zone
     ///
-    /// This method ensures that all sliders have consistent visual length by:
-    /// - Measuring the display width of each symbol (some Unicode chars take 2+ columns)
-    /// - Tracking column positions rather than character counts
-    /// - Always filling exactly `area.width` columns
-    fn render_horizontal(&self, area: Rect, buf: &mut Buffer) {
-        if area.width < 1 {
-            return;
-        }
-
-        let percentage = self.percentage();
-        let bar_width = area.width as usize;
-
-        // Get display widths of symbols using unicode-width
-        // Most symbols are 1 column wide, but some (like emojis) can be 2 or more
-        let filled_width = self.filled_symbol.width().max(1);
-        let empty_width = self.empty_symbol.width().max(1);
-        let handle_width = self.handle_symbol.width().max(1);
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::Zone;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(90.0, 0.0, 100.0)
+    ///     .filled_color(Color::Green)
+    ///     .zone(Zone::new(80.0, 100.0, Color::Red).symbol("▓"));
+    /// ```
+    pub fn zone(mut self, zone: Zone) -> Self {
+        self.zones.push(zone);
+        self
+    }
 
-        // Calculate how many columns should be filled based on percentage
-        let filled_columns = (bar_width as f64 * percentage) as usize;
+    /// Sets whether zones apply per cell or to the whole fill
+    ///
+    /// With [`ZoneMode::PerCell`] the fill shows every zone it passes through;
+    /// with [`ZoneMode::WholeFill`] it takes the zone of the current value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::{Zone, ZoneMode};
+    /// use tui_slider::Slider;
+    ///
+    /// // The whole bar turns red once the value passes 80
+    /// let slider = Slider::new(90.0, 0.0, 100.0)
+    ///     .zone(Zone::new(80.0, 100.0, Color::Red))
+    ///     .zone_mode(ZoneMode::WholeFill);
+    /// ```
+    pub fn zone_mode(mut self, mode: ZoneMode) -> Self {
+        self.zone_mode = mode;
+        self
+    }
 
-        // Calculate the Y position for the bar based on horizontal_bar_alignment
-        let bar_y = match self.horizontal_bar_alignment {
+    /// Sets the tick marks and scale labels drawn next to the track
+    ///
+    /// The ruler takes its rows (horizontal) or columns (vertical) from the
+    /// slider area when there is room for it next to the bar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::tick::{TickSpacing, Ticks};
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0)
+    ///     .ticks(Ticks::new().major(TickSpacing::Interval(25.0)));
+    /// ```
+    pub fn ticks(mut self, ticks: Ticks) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    /// Sets the reference markers drawn on the track
+    ///
+    /// Markers are placed like tick marks, so they line up with the ruler. The
+    /// handle always stays on top: a marker under the handle only shows its label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::marker::Marker;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0).markers([
+    ///     Marker::new(70.0, "▼").color(Color::Green).label("target"),
+    ///     Marker::new(55.0, "◆").color(Color::DarkGray).label("saved"),
+    /// ]);
+    /// ```
+    pub fn markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
+    /// Adds a single reference marker
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::marker::Marker;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0).marker(Marker::new(50.0, "◆"));
+    /// ```
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    /// Sets the peak level drawn as an indicator over the fill
+    ///
+    /// The indicator sits on the cell holding the peak level. It is usually fed
+    /// from a [`MeterState`]; see [`Slider::from_meter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0).peak(85.0).show_handle(false);
+    /// ```
+    pub fn peak(mut self, peak: f64) -> Self {
+        self.peak = Some(peak.clamp(self.min, self.max));
+        self
+    }
+
+    /// Sets the symbol of the peak indicator
+    ///
+    /// Defaults to a line across the track.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0).peak(85.0).peak_symbol("▌");
+    /// ```
+    pub fn peak_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.peak_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets the color of the peak indicator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(40.0, 0.0, 100.0).peak(85.0).peak_color(Color::Yellow);
+    /// ```
+    pub fn peak_color(mut self, color: Color) -> Self {
+        self.peak_color = color;
+        self
+    }
+
+    /// Sets a secondary value drawn between the fill and the empty track
+    ///
+    /// Useful for buffered media or downloaded-but-not-installed progress. Only
+    /// the part beyond the main value shows; the handle stays at the main value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::Slider;
+    ///
+    /// // Played up to 30%, buffered up to 65%
+    /// let slider = Slider::new(30.0, 0.0, 100.0)
+    ///     .secondary_value(65.0)
+    ///     .secondary_color(Color::Gray);
+    /// ```
+    pub fn secondary_value(mut self, value: f64) -> Self {
+        self.secondary_value = Some(value.clamp(self.min, self.max));
+        self
+    }
+
+    /// Sets the symbol of the secondary fill
+    ///
+    /// Defaults to the filled symbol. In eighths mode a symbol wider than one
+    /// cell is drawn as a blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(30.0, 0.0, 100.0)
+    ///     .secondary_value(65.0)
+    ///     .secondary_symbol("═");
+    /// ```
+    pub fn secondary_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.secondary_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets the color of the secondary fill
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(30.0, 0.0, 100.0)
+    ///     .secondary_value(65.0)
+    ///     .secondary_color(Color::Blue);
+    /// ```
+    pub fn secondary_color(mut self, color: Color) -> Self {
+        self.secondary_color = color;
+        self
+    }
+
+    /// Sets the number of blank cells between segments of a segmented bar
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::Slider;
+    ///
+    /// // One symbol on, one cell off
+    /// let slider = Slider::default()
+    ///     .segmented(true)
+    ///     .segment_length(1)
+    ///     .segment_gap(1);
+    /// ```
+    pub fn segment_gap(mut self, gap: u16) -> Self {
+        self.segment_gap = gap;
+        self
+    }
+
+    /// Applies a [`SliderStyle`] preset
+    ///
+    /// Sets the filled, empty and handle symbols and colors as well as the
+    /// segmented flag in one call. Individual setters called afterwards
+    /// override the preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::style::SliderStyle;
+    /// use tui_slider::{Slider, SliderState};
+    ///
+    /// let state = SliderState::new(80.0, 0.0, 100.0);
+    /// let slider = Slider::from_state(&state)
+    ///     .style(SliderStyle::progress_health())
+    ///     .handle_color(Color::Yellow);
+    /// ```
+    pub fn style(mut self, style: SliderStyle) -> Self {
+        self.filled_symbol = style.filled_symbol.into_owned();
+        self.empty_symbol = style.empty_symbol.into_owned();
+        self.handle_symbol = style.handle_symbol.into_owned();
+        self.filled_style = self.filled_style.fg(style.filled_color);
+        self.style_overrides.filled = self.style_overrides.filled.fg(style.filled_color);
+        self.empty_style = self.empty_style.fg(style.empty_color);
+        self.style_overrides.empty = self.style_overrides.empty.fg(style.empty_color);
+        self.handle_style = self.handle_style.fg(style.handle_color);
+        self.style_overrides.handle = self.style_overrides.handle.fg(style.handle_color);
+        self.segmented = style.segmented;
+        self
+    }
+
+    /// Applies a theme's symbols and normal styles
+    ///
+    /// Builder calls after this one override the theme for this slider, in its
+    /// normal, focused and disabled looks alike. The focused and disabled styles
+    /// are patched over the theme's normal ones when rendering with
+    /// [`focused`](Self::focused) or [`disabled`](Self::disabled) set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_slider::theme::SliderTheme;
+    /// use tui_slider::Slider;
+    ///
+    /// let theme = SliderTheme::high_contrast();
+    /// let slider = Slider::new(30.0, 0.0, 100.0)
+    ///     .label("Brightness")
+    ///     .theme(&theme)
+    ///     .filled_color(Color::LightYellow)
+    ///     .focused(true);
+    /// ```
+    pub fn theme(mut self, theme: &SliderTheme) -> Self {
+        self.filled_symbol = theme.filled_symbol.to_string();
+        self.empty_symbol = theme.empty_symbol.to_string();
+        self.handle_symbol = theme.handle_symbol.to_string();
+        self.apply_theme_styles(theme.normal);
+        self.style_overrides = ThemeStyles::new();
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    /// Sets whether the slider is drawn with the theme's focused look
+    ///
+    /// Has no effect without a [`theme`](Self::theme).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::theme::SliderTheme;
+    /// use tui_slider::Slider;
+    ///
+    /// let selected = 1;
+    /// let sliders: Vec<Slider> = (0..3)
+    ///     .map(|index| {
+    ///         Slider::new(50.0, 0.0, 100.0)
+    ///             .theme(&SliderTheme::dark())
+    ///             .focused(index == selected)
+    ///     })
+    ///     .collect();
+    /// ```
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the slider is drawn with the theme's disabled look
+    ///
+    /// Takes precedence over [`focused`](Self::focused). This only changes how
+    /// the slider looks; input is still handled by the caller. Has no effect
+    /// without a [`theme`](Self::theme).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::theme::SliderTheme;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(50.0, 0.0, 100.0)
+    ///     .theme(&SliderTheme::light())
+    ///     .disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Replaces the part styles with a theme's
+    fn apply_theme_styles(&mut self, styles: ThemeStyles) {
+        self.filled_style = styles.filled;
+        self.empty_style = styles.empty;
+        self.handle_style = styles.handle;
+        self.label_style = styles.label;
+        self.value_style = styles.value;
+    }
+
+    /// Patches the theme's focused or disabled styles over the part styles
+    ///
+    /// Styles set by builder calls after [`theme`](Self::theme) are patched back
+    /// on top, so they win over the focused and disabled looks too.
+    fn with_theme_state(mut self) -> Self {
+        let overlay = self
+            .theme
+            .as_ref()
+            .and_then(|theme| theme.overlay(self.focused, self.disabled));
+        if let Some(overlay) = overlay {
+            let current = ThemeStyles {
+                filled: self.filled_style,
+                empty: self.empty_style,
+                handle: self.handle_style,
+                label: self.label_style,
+                value: self.value_style,
+            };
+            self.apply_theme_styles(current.patch(overlay).patch(self.style_overrides));
+        }
+        self
+    }
+
+    /// Sets the label position for vertical sliders
+    ///
+    /// For vertical sliders, the label can be positioned at the top or bottom.
+    /// This setting only affects vertical sliders; horizontal sliders ignore this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{Slider, SliderOrientation, VerticalLabelPosition};
+    ///
+    /// let slider = Slider::default()
+    ///     .orientation(SliderOrientation::Vertical)
+    ///     .label("Volume")
+    ///     .vertical_label_position(VerticalLabelPosition::Bottom);
+    /// ```
+    pub fn vertical_label_position(mut self, position: VerticalLabelPosition) -> Self {
+        self.vertical_label_position = position;
+        self
+    }
+
+    /// Sets the value position for vertical sliders
+    ///
+    /// For vertical sliders, the numeric value can be positioned at the top, middle, or bottom.
+    /// This setting only affects vertical sliders; horizontal sliders ignore this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{Slider, SliderOrientation, VerticalValuePosition};
+    ///
+    /// let slider = Slider::default()
+    ///     .orientation(SliderOrientation::Vertical)
+    ///     .show_value(true)
+    ///     .vertical_value_position(VerticalValuePosition::Top);
+    /// ```
+    pub fn vertical_value_position(mut self, position: VerticalValuePosition) -> Self {
+        self.vertical_value_position = position;
+        self
+    }
+
+    /// Sets the value alignment for vertical sliders
+    ///
+    /// For vertical sliders, the numeric value can be aligned left, center, or right.
+    /// This setting only affects vertical sliders; horizontal sliders use `value_alignment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{Slider, SliderOrientation, VerticalValueAlignment};
+    ///
+    /// let slider = Slider::default()
+    ///     .orientation(SliderOrientation::Vertical)
+    ///     .show_value(true)
+    ///     .vertical_value_alignment(VerticalValueAlignment::Left);
+    /// ```
+    pub fn vertical_value_alignment(mut self, alignment: VerticalValueAlignment) -> Self {
+        self.vertical_value_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the bar in horizontal sliders
+    ///
+    /// For horizontal sliders, the bar can be positioned at the top, center (middle),
+    /// or bottom of the available area. This setting only affects horizontal sliders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{Slider, SliderOrientation, HorizontalBarAlignment};
+    ///
+    /// let slider = Slider::default()
+    ///     .orientation(SliderOrientation::Horizontal)
+    ///     .horizontal_bar_alignment(HorizontalBarAlignment::Top);
+    /// ```
+    pub fn horizontal_bar_alignment(mut self, alignment: HorizontalBarAlignment) -> Self {
+        self.horizontal_bar_alignment = alignment;
+        self
+    }
+
+    /// Sets how many rows a horizontal track spans, or columns a vertical one
+    ///
+    /// The bar, handle, peak and markers are repeated across the thickness, which
+    /// is clipped to the area. A horizontal track is placed by its
+    /// [`horizontal_bar_alignment`](Self::horizontal_bar_alignment); a vertical
+    /// one is centered. From a thickness of three, full blocks on the outer rows
+    /// or columns are drawn as half blocks facing inward, so a solid track keeps
+    /// a half-cell margin. Zero is treated as one. Defaults to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::{symbols, Slider};
+    ///
+    /// let slider = Slider::new(60.0, 0.0, 100.0)
+    ///     .filled_symbol(symbols::FILLED_BLOCK)
+    ///     .empty_symbol(symbols::EMPTY_LIGHT_SHADE)
+    ///     .thickness(3);
+    /// ```
+    pub fn thickness(mut self, thickness: u16) -> Self {
+        self.thickness = thickness.max(1);
+        self
+    }
+
+    /// Draws the label and value outside the area, as older versions did
+    ///
+    /// By default the slider splits its area into rows for the label and value
+    /// and the track, and never draws outside it; the label and value are left
+    /// out when the area has no room for them besides the track. In the legacy
+    /// layout the track takes the whole area and the label and value go on the
+    /// rows just outside it: above a horizontal slider, and above or below a
+    /// vertical one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    /// use tui_slider::Slider;
+    ///
+    /// let area = Rect::new(0, 1, 12, 1);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+    /// Slider::new(50.0, 0.0, 100.0)
+    ///     .label("Volume")
+    ///     .legacy_layout(true)
+    ///     .render(area, &mut buf);
+    ///
+    /// // The label sits on the row above the area
+    /// assert_eq!(buf[(0, 0)].symbol(), "V");
+    /// ```
+    pub fn legacy_layout(mut self, legacy: bool) -> Self {
+        self.legacy_layout = legacy;
+        self
+    }
+
+    /// Returns the smallest size the slider can be drawn in without clipping
+    ///
+    /// Counts the track at its minimum length, the label and value, ticks and
+    /// their labels, marker labels, the thickness, and any block or border. The
+    /// value is measured at its widest across the range, at every step (or up to
+    /// a thousand evenly spaced values on very long ranges), so the size does
+    /// not change as it moves.
+    ///
+    /// With the [legacy layout](Self::legacy_layout) the label and value rows
+    /// are drawn outside the area but still counted here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Size;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(50.0, 0.0, 100.0).label("Volume").show_value(true);
+    /// // "Volume" and "100.0" two cells apart, above a one-row track
+    /// assert_eq!(slider.min_size(), Size::new(13, 2));
+    /// ```
+    pub fn min_size(&self) -> Size {
+        self.size_with_track_length(self.min_track_length())
+    }
+
+    /// Returns the size the slider looks best in
+    ///
+    /// Like [`min_size`](Self::min_size), with the track long enough to show
+    /// every major tick label and at least 20 cells wide (horizontal) or 10
+    /// rows tall (vertical).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{Constraint, Layout, Rect};
+    /// use tui_slider::{Slider, SliderOrientation};
+    ///
+    /// let slider = Slider::new(50.0, 0.0, 100.0)
+    ///     .orientation(SliderOrientation::Vertical)
+    ///     .label("Gain");
+    /// let size = slider.preferred_size();
+    /// let [column] = Layout::horizontal([Constraint::Length(size.width)])
+    ///     .areas(Rect::new(0, 0, 80, 24));
+    /// assert_eq!(column.width, 4);
+    /// ```
+    pub fn preferred_size(&self) -> Size {
+        let labels = self.major_tick_labels();
+        let length = match self.orientation {
+            SliderOrientation::Horizontal => {
+                let widths: u16 = labels.iter().map(|label| label.width() as u16).sum();
+                let gaps = labels.len().saturating_sub(1) as u16;
+                (widths + gaps).max(PREFERRED_HORIZONTAL_LENGTH)
+            }
+            SliderOrientation::Vertical => (labels.len() as u16).max(PREFERRED_VERTICAL_LENGTH),
+        };
+        self.size_with_track_length(length.max(self.min_track_length()))
+    }
+
+    /// Calculates the percentage (0.0 to 1.0) of the current value
+    fn percentage(&self) -> f64 {
+        if (self.max - self.min).abs() < f64::EPSILON {
+            return 0.0;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Calculates the percentage of the secondary value, 0.0 without one
+    fn secondary_percentage(&self) -> f64 {
+        match self.secondary_value {
+            Some(value) if (self.max - self.min).abs() >= f64::EPSILON => {
+                ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the symbol and style of the secondary fill
+    ///
+    /// The secondary fill sits on the empty track, so it keeps the empty style
+    /// apart from its color.
+    fn secondary_cell(&self) -> (&str, Style) {
+        let symbol = self
+            .secondary_symbol
+            .as_deref()
+            .unwrap_or(&self.filled_symbol);
+        (symbol, self.secondary_style())
+    }
+
+    /// Returns the style of the secondary fill
+    fn secondary_style(&self) -> Style {
+        self.empty_style.fg(self.secondary_color)
+    }
+
+    /// Renders a horizontal slider
+    ///
+    /// This method ensures that all sliders have consistent visual length by:
+    /// - Measuring the display width of each symbol (some Unicode chars take 2+ columns)
+    /// - Tracking column positions rather than character counts
+    /// - Always filling exactly `area.width` columns
+    fn render_horizontal(&self, area: Rect, buf: &mut Buffer) -> SliderLayout {
+        let thickness = self.thickness.min(area.height);
+
+        // Place the band of rows based on horizontal_bar_alignment
+        let band_y = match self.horizontal_bar_alignment {
             HorizontalBarAlignment::Top => area.y,
-            HorizontalBarAlignment::Center => area.y + (area.height / 2),
-            HorizontalBarAlignment::Bottom => area.y + area.height.saturating_sub(1),
+            HorizontalBarAlignment::Center => (area.y + area.height / 2) - thickness / 2,
+            HorizontalBarAlignment::Bottom => area.y + area.height.saturating_sub(thickness),
         };
+        let mut layout = self.render_horizontal_row(area, band_y, buf);
+        self.thicken_track(
+            &mut layout,
+            Rect::new(area.x, band_y, area.width, thickness),
+            buf,
+        );
+        layout
+    }
+
+    /// Renders a horizontal bar on the `bar_y` row
+    fn render_horizontal_row(&self, area: Rect, bar_y: u16, buf: &mut Buffer) -> SliderLayout {
+        let percentage = self.percentage();
+        let bar_width = area.width as usize;
+
+        // Get display widths of symbols using unicode-width
+        // Most symbols are 1 column wide, but some (like emojis) can be 2 or more
+        let filled_width = self.filled_symbol.width().max(1);
+        let empty_width = self.empty_symbol.width().max(1);
+        let handle_width = self.handle_symbol.width().max(1);
+
+        // Calculate how many columns should be filled based on percentage
+        let filled_columns = (bar_width as f64 * percentage) as usize;
+        let secondary_columns = (bar_width as f64 * self.secondary_percentage()) as usize;
+
+        let mut layout = SliderLayout::new(
+            Rect::new(area.x, bar_y, area.width, 1),
+            SliderOrientation::Horizontal,
+        );
+
+        if self.segmented {
+            self.render_horizontal_segments(area, bar_y, buf, &mut layout);
+            return layout;
+        }
+        match self.fill_mode {
+            FillMode::Symbols => {}
+            FillMode::Eighths => {
+                self.render_horizontal_eighths(area, bar_y, buf, &mut layout);
+                return layout;
+            }
+            FillMode::Braille => {
+                self.render_horizontal_braille(area, bar_y, buf, &mut layout);
+                return layout;
+            }
+        }
 
         // Render bar - track column position to ensure we fill exactly bar_width columns
         let mut current_x = area.x;
@@ -573,10 +1627,15 @@ impl<'a> Slider<'a> {
             let remaining_cols = bar_width - col;
 
             // Determine which symbol to use based on current position
-            let (symbol, color, symbol_width) = if col < filled_columns {
-                (&self.filled_symbol, self.filled_color, filled_width)
+            let (symbol, style, symbol_width) = if col < filled_columns {
+                let style = self.filled_cell_style(col, bar_width, filled_columns);
+                let symbol = self.filled_cell_symbol(col, bar_width);
+                (symbol, style, symbol.width().max(1))
+            } else if col < secondary_columns {
+                let (symbol, style) = self.secondary_cell();
+                (symbol, style, symbol.width().max(1))
             } else {
-                (&self.empty_symbol, self.empty_color, empty_width)
+                (self.empty_symbol.as_str(), self.empty_style, empty_width)
             };
 
             // If this symbol would exceed the bar width, fill remaining space
@@ -589,7 +1648,7 @@ impl<'a> Slider<'a> {
             }
 
             // Render the symbol at the calculated Y position
-            buf.set_string(current_x, bar_y, symbol, Style::default().fg(color));
+            buf.set_string(current_x, bar_y, symbol, style);
             current_x += symbol_width as u16;
             col += symbol_width;
         }
@@ -620,22 +1679,32 @@ impl<'a> Slider<'a> {
 
             // Only render handle if it fits within the area
             if handle_x >= area.x && handle_x + handle_width as u16 <= area.x + area.width {
-                buf.set_string(
-                    handle_x,
-                    bar_y,
-                    &self.handle_symbol,
-                    Style::default().fg(self.handle_color),
-                );
+                buf.set_string(handle_x, bar_y, &self.handle_symbol, self.handle_style);
+                layout.handle = Some(Position::new(handle_x, bar_y));
             }
         }
+
+        layout
     }
 
     /// Renders a vertical slider
-    fn render_vertical(&self, area: Rect, buf: &mut Buffer) {
-        if area.height < 1 {
-            return;
-        }
+    fn render_vertical(&self, area: Rect, buf: &mut Buffer) -> SliderLayout {
+        let mut layout = self.render_vertical_column(area, buf);
+        let lane_width = layout.track.width.max(1);
+        let lanes = self.thickness.min(area.width / lane_width).max(1);
+        let band_width = lane_width * lanes;
+        let band = Rect::new(
+            area.x + area.width.saturating_sub(band_width) / 2,
+            area.y,
+            band_width.min(area.width),
+            area.height,
+        );
+        self.thicken_track(&mut layout, band, buf);
+        layout
+    }
 
+    /// Renders a vertical bar centered in the area
+    fn render_vertical_column(&self, area: Rect, buf: &mut Buffer) -> SliderLayout {
         let percentage = self.percentage();
         let bar_height = area.height as usize;
 
@@ -646,12 +1715,38 @@ impl<'a> Slider<'a> {
 
         // Calculate how many rows should be filled based on percentage
         let filled_rows = (bar_height as f64 * percentage) as usize;
+        let secondary_rows = (bar_height as f64 * self.secondary_percentage()) as usize;
 
         // Use the maximum width of all symbols (filled, empty, and handle) for consistent centering
         let max_symbol_width = filled_width.max(empty_width).max(handle_width) as u16;
 
         // Center all symbols based on the maximum symbol width for perfect alignment
         let base_x = area.x + (area.width.saturating_sub(max_symbol_width)) / 2;
+        let mut layout = SliderLayout::new(
+            Rect::new(
+                base_x,
+                area.y,
+                max_symbol_width.min(area.x + area.width - base_x),
+                area.height,
+            ),
+            SliderOrientation::Vertical,
+        );
+
+        if self.segmented {
+            self.render_vertical_segments(area, base_x, max_symbol_width, buf, &mut layout);
+            return layout;
+        }
+        match self.fill_mode {
+            FillMode::Symbols => {}
+            FillMode::Eighths => {
+                self.render_vertical_eighths(area, buf, &mut layout);
+                return layout;
+            }
+            FillMode::Braille => {
+                self.render_vertical_braille(area, buf, &mut layout);
+                return layout;
+            }
+        }
 
         // Render bar from bottom to top, track row position
         let mut current_y = area.y + area.height - 1;
@@ -665,10 +1760,18 @@ impl<'a> Slider<'a> {
             let remaining_rows = bar_height - row;
 
             // Determine which symbol to use based on current position
-            let (symbol, color, symbol_height) = if row < filled_rows {
-                (&self.filled_symbol, self.filled_color, filled_width)
+            let (symbol, style, symbol_height) = if row < filled_rows {
+                let style = self.filled_cell_style(row, bar_height, filled_rows);
+                (
+                    self.filled_cell_symbol(row, bar_height),
+                    style,
+                    filled_width,
+                )
+            } else if row < secondary_rows {
+                let (symbol, style) = self.secondary_cell();
+                (symbol, style, 1)
             } else {
-                (&self.empty_symbol, self.empty_color, empty_width)
+                (self.empty_symbol.as_str(), self.empty_style, empty_width)
             };
 
             // If this symbol would exceed the bar height, fill remaining space
@@ -687,7 +1790,7 @@ impl<'a> Slider<'a> {
             let symbol_x = base_x + (max_symbol_width.saturating_sub(symbol_width)) / 2;
 
             // Render the symbol
-            buf.set_string(symbol_x, current_y, symbol, Style::default().fg(color));
+            buf.set_string(symbol_x, current_y, symbol, style);
             current_y = current_y.saturating_sub(symbol_height as u16);
             row += symbol_height;
         }
@@ -711,23 +1814,451 @@ impl<'a> Slider<'a> {
                     break;
                 }
 
-                handle_y = handle_y.saturating_sub(symbol_height as u16);
-                accumulated_rows += symbol_height;
+                handle_y = handle_y.saturating_sub(symbol_height as u16);
+                accumulated_rows += symbol_height;
+            }
+
+            // Only render handle if it fits within the area
+            if handle_y >= area.y && handle_y < area.y + area.height {
+                // Center the handle within the max symbol width area for consistent alignment
+                let handle_width = self.handle_symbol.width() as u16;
+                let handle_x = base_x + (max_symbol_width.saturating_sub(handle_width)) / 2;
+
+                buf.set_string(handle_x, handle_y, &self.handle_symbol, self.handle_style);
+                layout.handle = Some(Position::new(handle_x, handle_y));
+            }
+        }
+
+        layout
+    }
+
+    /// Repeats the rendered track across `band` and records the band as the track
+    ///
+    /// The band holds whole copies of the track: rows for a horizontal slider,
+    /// lanes of the track's width for a vertical one. Nothing changes for a band
+    /// the size of the track.
+    fn thicken_track(&self, layout: &mut SliderLayout, band: Rect, buf: &mut Buffer) {
+        let track = layout.track;
+        if band == track || track.width == 0 || track.height == 0 {
+            return;
+        }
+        let cells: Vec<_> = track
+            .positions()
+            .map(|position| buf[position].clone())
+            .collect();
+        let lanes = self.track_lanes(band, track.width);
+        let count = lanes.len();
+        for (index, lane) in lanes.into_iter().enumerate() {
+            let edge = match (self.orientation, index) {
+                _ if count < 3 => None,
+                (SliderOrientation::Horizontal, 0) => Some(LOWER_HALF_BLOCK),
+                (SliderOrientation::Horizontal, _) if index == count - 1 => Some(UPPER_HALF_BLOCK),
+                (SliderOrientation::Vertical, 0) => Some(RIGHT_HALF_BLOCK),
+                (SliderOrientation::Vertical, _) if index == count - 1 => Some(LEFT_HALF_BLOCK),
+                _ => None,
+            };
+            for (position, cell) in lane.positions().zip(&cells) {
+                if !buf.area.contains(position) {
+                    continue;
+                }
+                let target = &mut buf[position];
+                *target = cell.clone();
+                if let Some(edge) = edge.filter(|_| cell.symbol() == FILLED_BLOCK) {
+                    target.set_symbol(edge);
+                }
+            }
+        }
+
+        layout.handle = layout.handle.map(|handle| {
+            Position::new(band.x + (handle.x - track.x), band.y + (handle.y - track.y))
+        });
+        layout.track = band;
+    }
+
+    /// Splits a track into the single-cell-thick lanes it repeats across
+    ///
+    /// Rows for a horizontal slider, columns of `lane_width` for a vertical one.
+    fn track_lanes(&self, track: Rect, lane_width: u16) -> Vec<Rect> {
+        match self.orientation {
+            SliderOrientation::Horizontal => track.rows().collect(),
+            SliderOrientation::Vertical => {
+                let lane_width = lane_width.clamp(1, track.width.max(1));
+                (0..track.width / lane_width)
+                    .map(|lane| {
+                        Rect::new(
+                            track.x + lane * lane_width,
+                            track.y,
+                            lane_width,
+                            track.height,
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the cells covered by the handle across the whole track thickness
+    fn handle_area(&self, layout: &SliderLayout) -> Option<Rect> {
+        let handle = layout.handle?;
+        let track = layout.track;
+        Some(match self.orientation {
+            SliderOrientation::Horizontal => Rect::new(
+                handle.x,
+                track.y,
+                self.handle_symbol.width().max(1) as u16,
+                track.height,
+            ),
+            SliderOrientation::Vertical => Rect::new(track.x, handle.y, track.width, 1),
+        })
+    }
+
+    /// Returns the width of one lane of a vertical track
+    fn lane_width(&self) -> u16 {
+        self.filled_symbol
+            .width()
+            .max(self.empty_symbol.width())
+            .max(self.handle_symbol.width())
+            .max(1) as u16
+    }
+
+    /// Returns the style of the filled cell at `cell` along a track of `cells` cells
+    ///
+    /// `filled` is the number of cells holding any fill. A matching zone takes
+    /// precedence over the gradient, which takes precedence over the filled style's
+    /// color.
+    fn filled_cell_style(&self, cell: usize, cells: usize, filled: usize) -> Style {
+        if let Some(zone) = self.zone_for_cell(cell, cells) {
+            return self.filled_style.fg(zone.color());
+        }
+        match &self.gradient {
+            Some(gradient) => self
+                .filled_style
+                .fg(gradient.cell_color(cell, cells, filled)),
+            None => self.filled_style,
+        }
+    }
+
+    /// Returns the filled symbol for the cell at `cell` along a track of `cells` cells
+    fn filled_cell_symbol(&self, cell: usize, cells: usize) -> &str {
+        self.zone_for_cell(cell, cells)
+            .and_then(Zone::filled_symbol)
+            .unwrap_or(&self.filled_symbol)
+    }
+
+    /// Returns the zone applying to the cell at `cell` along a track of `cells` cells
+    fn zone_for_cell(&self, cell: usize, cells: usize) -> Option<&Zone> {
+        if self.zones.is_empty() {
+            return None;
+        }
+        let value = match self.zone_mode {
+            // Classify each cell by the value at its centre
+            ZoneMode::PerCell => {
+                self.min + (cell as f64 + 0.5) / cells.max(1) as f64 * (self.max - self.min)
+            }
+            ZoneMode::WholeFill => self.value,
+        };
+        zone_at(&self.zones, value)
+    }
+
+    /// Returns the secondary symbol if it fits in a single cell, otherwise a blank
+    fn single_cell_secondary_symbol(&self) -> &str {
+        match self.secondary_cell().0 {
+            symbol if symbol.width() == 1 => symbol,
+            _ => " ",
+        }
+    }
+
+    /// Returns the empty symbol if it fits in a single cell, otherwise a blank
+    fn single_cell_empty_symbol(&self) -> &str {
+        if self.empty_symbol.width() == 1 {
+            &self.empty_symbol
+        } else {
+            " "
+        }
+    }
+
+    /// Renders a horizontal bar with a partial eighth block at the boundary cell
+    fn render_horizontal_eighths(
+        &self,
+        area: Rect,
+        bar_y: u16,
+        buf: &mut Buffer,
+        layout: &mut SliderLayout,
+    ) {
+        let bar_width = area.width as usize;
+        let (full_cells, eighths) =
+            FillMode::Eighths.split(bar_width, self.percentage(), SliderOrientation::Horizontal);
+        let empty_symbol = self.single_cell_empty_symbol();
+        let filled_cells = full_cells + usize::from(eighths > 0);
+        let secondary_cells = (bar_width as f64 * self.secondary_percentage()) as usize;
+        let secondary_symbol = self.single_cell_secondary_symbol();
+
+        for col in 0..bar_width {
+            let x = area.x + col as u16;
+            let fill_style = self.filled_cell_style(col, bar_width, filled_cells);
+            let (symbol, style) = if col < full_cells {
+                (FILLED_BLOCK, fill_style)
+            } else if col == full_cells && eighths > 0 {
+                (HORIZONTAL_EIGHTHS[eighths - 1], fill_style)
+            } else if col < secondary_cells {
+                (secondary_symbol, self.secondary_style())
+            } else {
+                (empty_symbol, self.empty_style)
+            };
+            buf.set_string(x, bar_y, symbol, style);
+        }
+
+        if self.show_handle && full_cells < bar_width {
+            let handle_x = area.x + full_cells as u16;
+            if self.handle_symbol.width() <= bar_width - full_cells {
+                buf.set_string(handle_x, bar_y, &self.handle_symbol, self.handle_style);
+                layout.handle = Some(Position::new(handle_x, bar_y));
+            }
+        }
+    }
+
+    /// Renders a vertical bar bottom to top, with a partial eighth block at the
+    /// boundary cell
+    fn render_vertical_eighths(&self, area: Rect, buf: &mut Buffer, layout: &mut SliderLayout) {
+        let bar_height = area.height as usize;
+        let (full_cells, eighths) =
+            FillMode::Eighths.split(bar_height, self.percentage(), SliderOrientation::Vertical);
+        let empty_symbol = self.single_cell_empty_symbol();
+        let track = layout.track;
+        let bottom = area.y + area.height - 1;
+        let filled_cells = full_cells + usize::from(eighths > 0);
+        let secondary_cells = (bar_height as f64 * self.secondary_percentage()) as usize;
+        let secondary_symbol = self.single_cell_secondary_symbol();
+
+        for row in 0..bar_height {
+            let y = bottom - row as u16;
+            let fill_style = self.filled_cell_style(row, bar_height, filled_cells);
+            let (symbol, style) = if row < full_cells {
+                (FILLED_BLOCK, fill_style)
+            } else if row == full_cells && eighths > 0 {
+                (VERTICAL_EIGHTHS[eighths - 1], fill_style)
+            } else if row < secondary_cells {
+                (secondary_symbol, self.secondary_style())
+            } else {
+                (empty_symbol, self.empty_style)
+            };
+            for x in track.x..track.x + track.width {
+                buf.set_string(x, y, symbol, style);
+            }
+        }
+
+        if self.show_handle && full_cells < bar_height {
+            let handle_y = bottom - full_cells as u16;
+            let handle_x = track.x
+                + track
+                    .width
+                    .saturating_sub(self.handle_symbol.width() as u16)
+                    / 2;
+            buf.set_string(handle_x, handle_y, &self.handle_symbol, self.handle_style);
+            layout.handle = Some(Position::new(handle_x, handle_y));
+        }
+    }
+
+    /// Renders a horizontal bar in braille dots, two dot columns per cell
+    fn render_horizontal_braille(
+        &self,
+        area: Rect,
+        bar_y: u16,
+        buf: &mut Buffer,
+        layout: &mut SliderLayout,
+    ) {
+        let orientation = SliderOrientation::Horizontal;
+        let cells = area.width as usize;
+        let (filled, secondary, handle) = self.braille_levels(cells, orientation);
+
+        for (col, x) in (area.x..area.x + area.width).enumerate() {
+            let (symbol, style) =
+                self.braille_symbol(col, cells, (filled, secondary), handle, orientation);
+            buf.set_string(x, bar_y, symbol.to_string(), style);
+        }
+
+        if let Some(handle) = handle {
+            let levels = FillMode::Braille.levels_per_cell(orientation);
+            layout.handle = Some(Position::new(area.x + (handle / levels) as u16, bar_y));
+        }
+    }
+
+    /// Renders a vertical bar in braille dots, four dot rows per cell, bottom to top
+    fn render_vertical_braille(&self, area: Rect, buf: &mut Buffer, layout: &mut SliderLayout) {
+        let orientation = SliderOrientation::Vertical;
+        let cells = area.height as usize;
+        let (filled, secondary, handle) = self.braille_levels(cells, orientation);
+        let track = layout.track;
+        let bottom = area.y + area.height - 1;
+
+        for row in 0..cells {
+            let y = bottom - row as u16;
+            let (symbol, style) =
+                self.braille_symbol(row, cells, (filled, secondary), handle, orientation);
+            for x in track.x..track.x + track.width {
+                buf.set_string(x, y, symbol.to_string(), style);
+            }
+        }
+
+        if let Some(handle) = handle {
+            let levels = FillMode::Braille.levels_per_cell(orientation);
+            let handle_x = track.x + track.width.saturating_sub(1) / 2;
+            layout.handle = Some(Position::new(handle_x, bottom - (handle / levels) as u16));
+        }
+    }
+
+    /// Returns the filled and secondary dot lines and the dot line of the handle
+    /// for a braille track
+    ///
+    /// The handle sits on the first unfilled dot line, or the last one when full.
+    fn braille_levels(
+        &self,
+        cells: usize,
+        orientation: SliderOrientation,
+    ) -> (usize, usize, Option<usize>) {
+        let total = cells * FillMode::Braille.levels_per_cell(orientation);
+        let filled = FillMode::Braille.filled_levels(cells, self.percentage(), orientation);
+        let secondary =
+            FillMode::Braille.filled_levels(cells, self.secondary_percentage(), orientation);
+        let handle = (self.show_handle && total > 0).then(|| filled.min(total - 1));
+        (filled, secondary, handle)
+    }
+
+    /// Returns the braille glyph and color for the cell at `index` along the track
+    ///
+    /// `filled` and `secondary` count the dot lines of the whole track.
+    fn braille_symbol(
+        &self,
+        index: usize,
+        cells: usize,
+        (filled, secondary): (usize, usize),
+        handle: Option<usize>,
+        orientation: SliderOrientation,
+    ) -> (char, Style) {
+        let levels = FillMode::Braille.levels_per_cell(orientation);
+        let start = index * levels;
+        let cell_filled = filled.saturating_sub(start).min(levels);
+        let cell_secondary = secondary.saturating_sub(start).min(levels);
+        let cell_handle = handle
+            .filter(|handle| (start..start + levels).contains(handle))
+            .map(|handle| handle - start);
+
+        let style = if cell_handle.is_some() {
+            self.handle_style
+        } else if cell_filled > 0 {
+            self.filled_cell_style(index, cells, filled.div_ceil(levels))
+        } else if cell_secondary > 0 {
+            self.secondary_style()
+        } else {
+            self.empty_style
+        };
+        let dots = cell_filled.max(cell_secondary);
+        (braille_cell(dots, cell_handle, orientation), style)
+    }
+
+    /// Renders a horizontal bar as whole segments separated by gaps
+    ///
+    /// Each segment holds `segment_length` symbols, each as wide as the widest of
+    /// the filled and empty symbols.
+    fn render_horizontal_segments(
+        &self,
+        area: Rect,
+        bar_y: u16,
+        buf: &mut Buffer,
+        layout: &mut SliderLayout,
+    ) {
+        let filled_width = self.filled_symbol.width().max(1);
+        let empty_width = self.empty_symbol.width().max(1);
+        let handle_width = self.handle_symbol.width().max(1);
+        let unit = filled_width.max(empty_width);
+
+        let segments = Segments::new(
+            area.width as usize,
+            self.segment_length as usize * unit,
+            self.segment_gap as usize,
+        );
+        let filled_segments = segments.filled(self.percentage());
+        let secondary_segments = segments.filled(self.secondary_percentage());
+
+        for col in 0..area.width as usize {
+            let x = area.x + col as u16;
+            match segments.segment_at(col) {
+                Some((index, offset)) if offset % unit == 0 => {
+                    let (symbol, style) = if index < filled_segments {
+                        let filled_cells = segments.filled_cells(filled_segments);
+                        let style = self.filled_cell_style(col, area.width as usize, filled_cells);
+                        (self.filled_cell_symbol(col, area.width as usize), style)
+                    } else if index < secondary_segments {
+                        self.secondary_cell()
+                    } else {
+                        (self.empty_symbol.as_str(), self.empty_style)
+                    };
+                    let remaining = area.width as usize - col;
+                    if symbol.width().max(1) <= remaining {
+                        buf.set_string(x, bar_y, symbol, style);
+                    } else {
+                        buf.set_string(x, bar_y, " ", Style::default());
+                    }
+                }
+                // Covered by a wide symbol drawn in an earlier column
+                Some(_) => {}
+                None => {
+                    buf.set_string(x, bar_y, " ", Style::default());
+                }
+            }
+        }
+
+        if self.show_handle && segments.count > 0 {
+            let offset = segments.handle_offset(filled_segments, handle_width);
+            if offset + handle_width <= area.width as usize {
+                let handle_x = area.x + offset as u16;
+                buf.set_string(handle_x, bar_y, &self.handle_symbol, self.handle_style);
+                layout.handle = Some(Position::new(handle_x, bar_y));
             }
+        }
+    }
 
-            // Only render handle if it fits within the area
-            if handle_y >= area.y && handle_y < area.y + area.height {
-                // Center the handle within the max symbol width area for consistent alignment
-                let handle_width = self.handle_symbol.width() as u16;
-                let handle_x = base_x + (max_symbol_width.saturating_sub(handle_width)) / 2;
+    /// Renders a vertical bar as whole segments separated by gaps, bottom to top
+    fn render_vertical_segments(
+        &self,
+        area: Rect,
+        base_x: u16,
+        max_symbol_width: u16,
+        buf: &mut Buffer,
+        layout: &mut SliderLayout,
+    ) {
+        let segments = Segments::new(
+            area.height as usize,
+            self.segment_length as usize,
+            self.segment_gap as usize,
+        );
+        let filled_segments = segments.filled(self.percentage());
+        let secondary_segments = segments.filled(self.secondary_percentage());
+        let bottom = area.y + area.height - 1;
+
+        for row in 0..area.height as usize {
+            let y = bottom - row as u16;
+            let (symbol, style) = match segments.segment_at(row) {
+                Some((index, _)) if index < filled_segments => {
+                    let filled_cells = segments.filled_cells(filled_segments);
+                    let style = self.filled_cell_style(row, area.height as usize, filled_cells);
+                    (self.filled_cell_symbol(row, area.height as usize), style)
+                }
+                Some((index, _)) if index < secondary_segments => self.secondary_cell(),
+                Some(_) => (self.empty_symbol.as_str(), self.empty_style),
+                None => (" ", Style::default()),
+            };
+            let symbol_x = base_x + max_symbol_width.saturating_sub(symbol.width() as u16) / 2;
+            buf.set_string(symbol_x, y, symbol, style);
+        }
 
-                buf.set_string(
-                    handle_x,
-                    handle_y,
-                    &self.handle_symbol,
-                    Style::default().fg(self.handle_color),
-                );
-            }
+        if self.show_handle && segments.count > 0 {
+            let offset = segments.handle_offset(filled_segments, 1);
+            let handle_y = bottom - offset as u16;
+            let handle_x =
+                base_x + max_symbol_width.saturating_sub(self.handle_symbol.width() as u16) / 2;
+            buf.set_string(handle_x, handle_y, &self.handle_symbol, self.handle_style);
+            layout.handle = Some(Position::new(handle_x, handle_y));
         }
     }
 
@@ -735,7 +2266,10 @@ impl<'a> Slider<'a> {
     fn render_label_and_value(&self, area: Rect, buf: &mut Buffer) {
         // This is only used for horizontal sliders now
         let label_info = self.calculate_label_info(area);
-        let value_info = self.calculate_value_info(area, true);
+        let value_info = match self.value_placement {
+            ValuePlacement::Fixed => self.calculate_value_info(area, true),
+            ValuePlacement::FollowHandle => None,
+        };
         let (label_x, value_x) = self.resolve_positions(area, true, &label_info, &value_info);
         self.render_label(buf, area, true, label_x);
         self.render_value(buf, area, true, value_x, value_info);
@@ -748,10 +2282,13 @@ impl<'a> Slider<'a> {
             self.label.is_some() && self.vertical_label_position == VerticalLabelPosition::Top;
         let label_at_bottom =
             self.label.is_some() && self.vertical_label_position == VerticalLabelPosition::Bottom;
-        let value_at_top =
-            self.show_value && self.vertical_value_position == VerticalValuePosition::Top;
-        let value_at_bottom =
-            self.show_value && self.vertical_value_position == VerticalValuePosition::Bottom;
+        let fixed_value = self.value_placement == ValuePlacement::Fixed;
+        let value_at_top = self.show_value
+            && fixed_value
+            && self.vertical_value_position == VerticalValuePosition::Top;
+        let value_at_bottom = self.show_value
+            && fixed_value
+            && self.vertical_value_position == VerticalValuePosition::Bottom;
 
         let both_at_top = label_at_top && value_at_top;
         let both_at_bottom = label_at_bottom && value_at_bottom;
@@ -782,14 +2319,15 @@ impl<'a> Slider<'a> {
             let label_x = area.x + (area.width.saturating_sub(label_width)) / 2;
 
             if self.is_within_buffer(buf, label_x, label_y) {
-                buf.set_string(label_x, label_y, label, Style::default());
+                buf.set_string(label_x, label_y, label, self.label_style);
             }
         }
 
-        // Render value if enabled
-        if self.show_value {
-            let value_str = format!("{:.0}", self.value);
-            let value_width = value_str.len() as u16;
+        // Render value (or the edit field) if enabled; a value following the
+        // handle is drawn after the bar
+        if fixed_value && (self.show_value || self.editor.is_some()) {
+            let value_str = self.value_text();
+            let value_width = value_str.width() as u16 + self.edit_cursor_padding();
 
             // Calculate Y position based on vertical position setting
             // When stacked with label, adjust position
@@ -823,7 +2361,7 @@ impl<'a> Slider<'a> {
             };
 
             if self.is_within_buffer(buf, value_x, value_y) {
-                buf.set_string(value_x, value_y, &value_str, Style::default());
+                self.render_value_text(buf, value_x, value_y, &value_str);
             }
         }
     }
@@ -836,179 +2374,913 @@ impl<'a> Slider<'a> {
     }
 
     fn calculate_value_info(&self, area: Rect, is_horizontal: bool) -> Option<(u16, u16, String)> {
-        if !self.show_value {
+        if !self.show_value && self.editor.is_none() {
+            return None;
+        }
+
+        let value_str = self.value_text();
+        let value_width = value_str.width() as u16 + self.edit_cursor_padding();
+
+        let x_pos = if is_horizontal {
+            // If we have a label and value alignment is Left, add spacing after the label
+            if self.value_alignment == Alignment::Left && self.label.is_some() {
+                let label_width = self.label.as_ref().map(|l| l.width() as u16).unwrap_or(0);
+                let spacing = 2;
+                area.x + label_width + spacing
+            } else {
+                self.calculate_horizontal_value_position(area, value_width)
+            }
+        } else {
+            area.x + 2
+        };
+
+        Some((x_pos, value_width, value_str))
+    }
+
+    fn calculate_horizontal_value_position(&self, area: Rect, value_width: u16) -> u16 {
+        match self.value_alignment {
+            Alignment::Left => area.x,
+            Alignment::Center => area.x + (area.width.saturating_sub(value_width)) / 2,
+            Alignment::Right => area.x + area.width.saturating_sub(value_width),
+        }
+    }
+
+    fn resolve_positions(
+        &self,
+        area: Rect,
+        is_horizontal: bool,
+        label_info: &Option<(u16, u16)>,
+        value_info: &Option<(u16, u16, String)>,
+    ) -> (u16, u16) {
+        match (label_info, value_info) {
+            (Some((label_x, label_w)), Some((value_x, value_w, _))) => {
+                if is_horizontal && self.has_overlap(*label_x, *label_w, *value_x, *value_w) {
+                    self.adjust_for_overlap(area, *label_x, *label_w, *value_w)
+                } else {
+                    (*label_x, *value_x)
+                }
+            }
+            _ => (
+                label_info.map(|(x, _)| x).unwrap_or(area.x),
+                value_info.as_ref().map(|(x, _, _)| *x).unwrap_or(area.x),
+            ),
+        }
+    }
+
+    fn has_overlap(&self, label_x: u16, label_w: u16, value_x: u16, value_w: u16) -> bool {
+        let label_end = label_x + label_w;
+        let value_end = value_x + value_w;
+        !(label_end <= value_x || value_end <= label_x)
+    }
+
+    fn adjust_for_overlap(
+        &self,
+        area: Rect,
+        label_x: u16,
+        label_w: u16,
+        value_w: u16,
+    ) -> (u16, u16) {
+        match self.value_alignment {
+            Alignment::Center | Alignment::Right => {
+                // Keep label on left, move value to right
+                (label_x, area.x + area.width.saturating_sub(value_w))
+            }
+            Alignment::Left => {
+                // Try to add spacing between label and value
+                let spacing = 2;
+                let label_end = label_x + label_w;
+
+                if label_end + spacing + value_w <= area.x + area.width {
+                    (label_x, label_end + spacing)
+                } else {
+                    // Not enough space, put value on right edge
+                    (label_x, area.x + area.width.saturating_sub(value_w))
+                }
+            }
+        }
+    }
+
+    /// Renders the label text at the specified position
+    fn render_label(&self, buf: &mut Buffer, area: Rect, is_horizontal: bool, label_x: u16) {
+        if let Some(ref label) = self.label {
+            let label_y = if is_horizontal {
+                area.y.saturating_sub(1)
+            } else {
+                area.y
+            };
+
+            if self.is_within_buffer(buf, label_x, label_y) {
+                buf.set_string(label_x, label_y, label, self.label_style);
+            }
+        }
+    }
+
+    /// Renders the value text at the specified position
+    fn render_value(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        is_horizontal: bool,
+        value_x: u16,
+        value_info: Option<(u16, u16, String)>,
+    ) {
+        if let Some((_, _, value_str)) = value_info {
+            let value_y = if is_horizontal {
+                area.y.saturating_sub(1)
+            } else {
+                area.y + area.height
+            };
+
+            if self.is_within_buffer(buf, value_x, value_y) {
+                self.render_value_text(buf, value_x, value_y, &value_str);
+            }
+        }
+    }
+
+    /// Returns the text to show in place of the value: the edit field contents
+    /// while editing, the formatted value otherwise
+    fn value_text(&self) -> String {
+        match self.editor {
+            Some(ref editor) => editor.text().to_string(),
+            None => self.formatted_value(),
+        }
+    }
+
+    /// Formats the value with the configured formatter
+    ///
+    /// Without one, horizontal sliders show one decimal and vertical sliders none.
+    fn formatted_value(&self) -> String {
+        self.format_value(self.value)
+    }
+
+    /// Formats any value in the range the way
+    /// [`formatted_value`](Self::formatted_value) does
+    fn format_value(&self, value: f64) -> String {
+        let context = self.format_context();
+        match &self.value_formatter {
+            Some(formatter) => formatter.format(value, &context),
+            None => match self.orientation {
+                SliderOrientation::Horizontal => context.format_number(value, 1),
+                SliderOrientation::Vertical => context.format_number(value, 0),
+            },
+        }
+    }
+
+    /// Returns the rows the label and fixed value take above and below the track
+    fn text_rows(&self) -> (u16, u16) {
+        let fixed_value = (self.show_value || self.editor.is_some())
+            && self.value_placement == ValuePlacement::Fixed;
+        match self.orientation {
+            SliderOrientation::Horizontal => (u16::from(self.label.is_some() || fixed_value), 0),
+            SliderOrientation::Vertical => {
+                let label_top = self.vertical_label_position == VerticalLabelPosition::Top;
+                let value_top = self.vertical_value_position == VerticalValuePosition::Top;
+                let value_bottom = self.vertical_value_position == VerticalValuePosition::Bottom;
+                let label = self.label.is_some();
+                (
+                    u16::from(label && label_top) + u16::from(fixed_value && value_top),
+                    u16::from(label && !label_top) + u16::from(fixed_value && value_bottom),
+                )
+            }
+        }
+    }
+
+    /// Returns the shortest track that still fits the handle
+    fn min_track_length(&self) -> u16 {
+        match self.orientation {
+            SliderOrientation::Horizontal => self.handle_symbol.width().max(1) as u16,
+            SliderOrientation::Vertical => 1,
+        }
+    }
+
+    /// Returns the labels of the major ticks, if ticks are shown with labels
+    fn major_tick_labels(&self) -> Vec<String> {
+        match self.ticks {
+            Some(ref ticks) if ticks.has_labels() => ticks
+                .major_ticks(&self.format_context(), self.step)
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the width of the value text at its widest across the range, or
+    /// zero when the value is hidden
+    ///
+    /// Every step is measured, or evenly spaced samples when the range has more
+    /// steps than [`MAX_WIDTH_SAMPLES`], along with both ends and the current
+    /// value.
+    fn widest_value(&self) -> u16 {
+        if !self.show_value && self.editor.is_none() {
+            return 0;
+        }
+        let range = self.max - self.min;
+        let steps = if self.step > 0.0 {
+            (range / self.step).ceil() as usize
+        } else {
+            MAX_WIDTH_SAMPLES
+        };
+        let samples = steps.clamp(1, MAX_WIDTH_SAMPLES);
+        let formatted = (0..=samples)
+            .map(|sample| {
+                if steps <= MAX_WIDTH_SAMPLES {
+                    (self.min + sample as f64 * self.step).min(self.max)
+                } else {
+                    self.min + range * sample as f64 / samples as f64
+                }
+            })
+            .chain([self.max, self.value])
+            .map(|value| self.format_value(value).width() as u16)
+            .max()
+            .unwrap_or(0);
+        formatted.max(self.value_text().width() as u16 + self.edit_cursor_padding())
+    }
+
+    /// Returns the cells taken up by the block and border around the slider
+    fn chrome_size(&self) -> Size {
+        let outer = Rect::new(0, 0, u16::MAX / 2, u16::MAX / 2);
+        let inner = match self.block {
+            Some(ref block) => block.inner(outer),
+            None => outer,
+        };
+        let inner = match self.border {
+            Some(ref border) => border.inner(inner),
+            None => inner,
+        };
+        Size::new(outer.width - inner.width, outer.height - inner.height)
+    }
+
+    /// Returns the size needed to draw the slider with a track of `length` cells
+    fn size_with_track_length(&self, length: u16) -> Size {
+        let label_width = self.label.as_ref().map_or(0, |label| label.width() as u16);
+        let value_width = self.widest_value();
+        let shows_value = value_width > 0;
+        let fixed_value = shows_value && self.value_placement == ValuePlacement::Fixed;
+        let follow_value = shows_value && self.value_placement == ValuePlacement::FollowHandle;
+        let marker_label_width = self
+            .markers
+            .iter()
+            .filter_map(|marker| marker.label_text())
+            .map(|label| label.width() as u16)
+            .max();
+        let tick_labels = self.major_tick_labels();
+
+        let size = match self.orientation {
+            SliderOrientation::Horizontal => {
+                let text_width = match (self.label.is_some(), fixed_value) {
+                    (true, true) => label_width + 2 + value_width,
+                    (true, false) => label_width,
+                    (false, true) => value_width,
+                    (false, false) => 0,
+                };
+                let (text_rows, _) = self.text_rows();
+                // Marker labels and a value following the handle go above the track
+                let above_track = u16::from(follow_value || marker_label_width.is_some());
+                let tick_rows = self
+                    .ticks
+                    .as_ref()
+                    .map_or(0, |ticks| 1 + u16::from(ticks.has_labels()));
+                // Each label is kept whole by clamping it to the track
+                let widest_label = tick_labels
+                    .iter()
+                    .map(|label| label.width() as u16)
+                    .chain(marker_label_width)
+                    .max()
+                    .unwrap_or(0);
+                Size::new(
+                    length.max(text_width).max(value_width).max(widest_label),
+                    text_rows + above_track + self.thickness + tick_rows,
+                )
+            }
+            SliderOrientation::Vertical => {
+                let track_width = self.lane_width() * self.thickness;
+                // Room for marker labels or the value beside the centered track
+                let beside = marker_label_width
+                    .into_iter()
+                    .chain(follow_value.then_some(value_width))
+                    .max()
+                    .map_or(0, |width| 2 * (width + 1) - 1);
+                let tick_columns = self.ticks.as_ref().map_or(0, |_| {
+                    1 + tick_labels
+                        .iter()
+                        .map(|label| label.width() as u16)
+                        .max()
+                        .unwrap_or(0)
+                });
+                let fixed_width = if fixed_value { value_width } else { 0 };
+                let (top, bottom) = self.text_rows();
+                let rows = top + bottom;
+                Size::new(
+                    (track_width + beside + tick_columns)
+                        .max(label_width)
+                        .max(fixed_width),
+                    length + rows,
+                )
+            }
+        };
+        let chrome = self.chrome_size();
+        Size::new(size.width + chrome.width, size.height + chrome.height)
+    }
+
+    /// Fills in the placeholders of a border title template
+    fn expand_border_title(&self, template: &str) -> String {
+        let percent = Percent::new(0).format(self.value, &self.format_context());
+        expand_title_template(
+            template,
+            self.label.as_deref().unwrap_or_default(),
+            &self.value_text(),
+            &percent,
+        )
+    }
+
+    /// Returns the range and number format values and tick labels are written in
+    fn format_context(&self) -> FormatContext {
+        FormatContext::new(self.min, self.max).number_format(self.number_format.unwrap_or_default())
+    }
+
+    /// Extra column reserved for the cursor when it sits after the last character
+    fn edit_cursor_padding(&self) -> u16 {
+        match self.editor {
+            Some(ref editor) if editor.cursor() >= editor.text().chars().count() => 1,
+            _ => 0,
+        }
+    }
+
+    /// Draws the value text, highlighting the cursor cell while editing
+    fn render_value_text(&self, buf: &mut Buffer, x: u16, y: u16, text: &str) {
+        buf.set_string(x, y, text, self.value_style);
+
+        if let Some(ref editor) = self.editor {
+            let cursor_x = x + editor.cursor() as u16;
+            if self.is_within_buffer(buf, cursor_x, y) {
+                let cursor_symbol = text
+                    .chars()
+                    .nth(editor.cursor())
+                    .map(String::from)
+                    .unwrap_or_else(|| " ".to_string());
+                buf.set_string(
+                    cursor_x,
+                    y,
+                    cursor_symbol,
+                    self.value_style.add_modifier(Modifier::REVERSED),
+                );
+            }
+        }
+    }
+
+    fn is_within_buffer(&self, buf: &Buffer, x: u16, y: u16) -> bool {
+        x >= buf.area.x
+            && x < buf.area.x + buf.area.width
+            && y >= buf.area.y
+            && y < buf.area.y + buf.area.height
+    }
+}
+
+/// Division of a track into whole segments separated by gaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segments {
+    /// Number of whole segments that fit on the track
+    count: usize,
+    /// Cells per segment
+    length: usize,
+    /// Blank cells between segments
+    gap: usize,
+}
+
+impl Segments {
+    /// Fits as many whole segments as possible into `track_len` cells
+    ///
+    /// A track shorter than one segment holds a single shortened segment.
+    fn new(track_len: usize, length: usize, gap: usize) -> Self {
+        let length = length.max(1);
+        let count = (track_len + gap) / (length + gap);
+        if count == 0 {
+            Self {
+                count: usize::from(track_len > 0),
+                length: track_len,
+                gap,
+            }
+        } else {
+            Self { count, length, gap }
+        }
+    }
+
+    fn period(&self) -> usize {
+        self.length + self.gap
+    }
+
+    /// Number of whole segments filled at the given percentage
+    fn filled(&self, percentage: f64) -> usize {
+        ((self.count as f64 * percentage).round() as usize).min(self.count)
+    }
+
+    /// Returns the segment index and offset within it for a cell, or `None` for gaps
+    fn segment_at(&self, cell: usize) -> Option<(usize, usize)> {
+        let index = cell / self.period();
+        let offset = cell % self.period();
+        (index < self.count && offset < self.length).then_some((index, offset))
+    }
+
+    /// Number of cells from the start of the track to the end of the filled segments
+    fn filled_cells(&self, filled: usize) -> usize {
+        (filled * self.period()).saturating_sub(self.gap)
+    }
+
+    /// Cell at which the handle starts: the boundary after the filled segments
+    fn handle_offset(&self, filled: usize, handle_len: usize) -> usize {
+        if filled == 0 {
+            return 0;
+        }
+        let boundary = filled * self.period() - self.gap;
+        let track_end = self.count * self.period() - self.gap;
+        boundary.min(track_end.saturating_sub(handle_len))
+    }
+}
+
+impl<'a> Default for Slider<'a> {
+    fn default() -> Self {
+        Self::new(0.0, 0.0, 100.0)
+    }
+}
+
+impl<'a> Slider<'a> {
+    /// Renders the block, label, value and bar, returning where the bar was drawn
+    fn render_slider(&self, area: Rect, buf: &mut Buffer) -> Option<SliderLayout> {
+        let area = match self.block {
+            Some(ref block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let area = match self.border {
+            Some(ref border) => {
+                match self.border_title {
+                    Some(ref template) => border
+                        .clone()
+                        .title(self.expand_border_title(template))
+                        .render(area, buf),
+                    None => border.render(area, buf),
+                }
+                border.inner(area)
+            }
+            None => area,
+        };
+
+        if area.width == 0 || area.height == 0 {
             return None;
         }
 
-        let value_str = format!("{:.1}", self.value);
-        let value_width = value_str.len() as u16;
-
-        let x_pos = if is_horizontal {
-            // If we have a label and value alignment is Left, add spacing after the label
-            if self.value_alignment == Alignment::Left && self.label.is_some() {
-                let label_width = self.label.as_ref().map(|l| l.width() as u16).unwrap_or(0);
-                let spacing = 2;
-                area.x + label_width + spacing
-            } else {
-                self.calculate_horizontal_value_position(area, value_width)
-            }
-        } else {
-            area.x + 2
+        // Everything else is kept within these bounds
+        let bounds = if self.legacy_layout { buf.area } else { area };
+        let (top, bottom) = self.text_rows();
+        let (area, show_text) = if self.legacy_layout || top + bottom == 0 {
+            (area, true)
+        } else if area.height > top + bottom {
+            let rows = Rect::new(area.x, area.y + top, area.width, area.height - top - bottom);
+            (rows, true)
+        } else {
+            (area, false)
+        };
+
+        // Render label and value if needed, on the rows reserved above and below
+        match self.orientation {
+            SliderOrientation::Horizontal if show_text => {
+                self.render_label_and_value(area, buf);
+            }
+            SliderOrientation::Vertical if show_text => {
+                self.render_vertical_label_and_value(area, buf);
+            }
+            _ => {}
+        }
+
+        // Leave room for the ruler next to the bar
+        let major_ticks = self
+            .ticks
+            .as_ref()
+            .map(|ticks| ticks.major_ticks(&self.format_context(), self.step))
+            .unwrap_or_default();
+        let bar_area = match self.ticks {
+            Some(ref ticks) => self.bar_area_without_ticks(area, ticks, &major_ticks),
+            None => area,
         };
 
-        Some((x_pos, value_width, value_str))
-    }
+        // Render the slider based on orientation
+        let layout = match self.orientation {
+            SliderOrientation::Horizontal => self.render_horizontal(bar_area, buf),
+            SliderOrientation::Vertical => self.render_vertical(bar_area, buf),
+        };
 
-    fn calculate_horizontal_value_position(&self, area: Rect, value_width: u16) -> u16 {
-        match self.value_alignment {
-            Alignment::Left => area.x,
-            Alignment::Center => area.x + (area.width.saturating_sub(value_width)) / 2,
-            Alignment::Right => area.x + area.width.saturating_sub(value_width),
+        if let Some(ref ticks) = self.ticks {
+            self.render_ticks(area, &layout, ticks, &major_ticks, buf);
+        }
+        self.render_peak(&layout, buf);
+        self.render_markers(area, &layout, buf);
+        if self.value_placement == ValuePlacement::FollowHandle {
+            self.render_value_at_handle(area, bounds, &layout, buf);
         }
+        Some(layout)
     }
 
-    fn resolve_positions(
+    /// Renders the value next to the handle, or where it would be when hidden
+    ///
+    /// Horizontal sliders try the row above the handle, then the same row past the
+    /// label, then the row below the bar. Vertical sliders try the right of the
+    /// track, then its left.
+    fn render_value_at_handle(
         &self,
         area: Rect,
-        is_horizontal: bool,
-        label_info: &Option<(u16, u16)>,
-        value_info: &Option<(u16, u16, String)>,
-    ) -> (u16, u16) {
-        match (label_info, value_info) {
-            (Some((label_x, label_w)), Some((value_x, value_w, _))) => {
-                if is_horizontal && self.has_overlap(*label_x, *label_w, *value_x, *value_w) {
-                    self.adjust_for_overlap(area, *label_x, *label_w, *value_w)
+        bounds: Rect,
+        layout: &SliderLayout,
+        buf: &mut Buffer,
+    ) {
+        if !self.show_value && self.editor.is_none() {
+            return;
+        }
+        let track = layout.track;
+        let anchor = layout.handle.unwrap_or_else(|| {
+            let percentage = self.percentage();
+            match self.orientation {
+                SliderOrientation::Horizontal => Position::new(
+                    track.x + (percentage * track.width.saturating_sub(1) as f64).round() as u16,
+                    track.y,
+                ),
+                SliderOrientation::Vertical => Position::new(
+                    track.x,
+                    track.bottom().saturating_sub(1)
+                        - (percentage * track.height.saturating_sub(1) as f64).round() as u16,
+                ),
+            }
+        });
+
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                let value_str = self.value_text();
+                let value_width = value_str.width() as u16 + self.edit_cursor_padding();
+                if value_width > area.width {
+                    return;
+                }
+                let handle_width = self.handle_symbol.width() as u16;
+                let centered = (anchor.x + handle_width / 2)
+                    .saturating_sub(value_width / 2)
+                    .clamp(area.x, area.right() - value_width);
+
+                // The label sits on the row reserved above the area, from its left edge
+                let label = self.label.as_ref().map(|label| {
+                    Rect::new(area.x, area.y.saturating_sub(1), label.width() as u16, 1)
+                });
+                let clear_of_label = |x: u16, y: u16| {
+                    let value = Rect::new(x, y, value_width, 1);
+                    label.map_or(true, |label| !label.intersects(value))
+                };
+
+                let above = track.y.saturating_sub(1);
+                let past_label = label.map(|label| label.right() + 1).unwrap_or(area.x);
+                let candidates = [
+                    (centered, above),
+                    (centered.max(past_label), above),
+                    (centered, track.bottom()),
+                ];
+                let position = candidates.into_iter().find(|&(x, y)| {
+                    !(track.y..track.bottom()).contains(&y)
+                        && x + value_width <= area.right()
+                        && (bounds.y..bounds.bottom()).contains(&y)
+                        && clear_of_label(x, y)
+                        && self.is_within_buffer(buf, x, y)
+                });
+                if let Some((x, y)) = position {
+                    self.render_value_text(buf, x, y, &value_str);
+                }
+            }
+            SliderOrientation::Vertical => {
+                let value_str = self.value_text();
+                let value_width = value_str.width() as u16 + self.edit_cursor_padding();
+                let y = anchor.y.clamp(area.y, area.bottom().saturating_sub(1));
+
+                let right = track.right() + 1;
+                let x = if right + value_width <= area.right() {
+                    Some(right)
                 } else {
-                    (*label_x, *value_x)
+                    track
+                        .x
+                        .checked_sub(value_width + 1)
+                        .filter(|&x| x >= area.x)
+                };
+                if let Some(x) = x {
+                    self.render_value_text(buf, x, y, &value_str);
                 }
             }
-            _ => (
-                label_info.map(|(x, _)| x).unwrap_or(area.x),
-                value_info.as_ref().map(|(x, _, _)| *x).unwrap_or(area.x),
-            ),
         }
     }
 
-    fn has_overlap(&self, label_x: u16, label_w: u16, value_x: u16, value_w: u16) -> bool {
-        let label_end = label_x + label_w;
-        let value_end = value_x + value_w;
-        !(label_end <= value_x || value_end <= label_x)
+    /// Renders the peak indicator on the cell holding the peak level
+    ///
+    /// Nothing is drawn for a peak at the minimum or under the handle.
+    fn render_peak(&self, layout: &SliderLayout, buf: &mut Buffer) {
+        let Some(peak) = self.peak else {
+            return;
+        };
+        let range = self.max - self.min;
+        let percentage = if range > 0.0 {
+            ((peak - self.min) / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let track = layout.track;
+        let horizontal = self.orientation.is_horizontal();
+        let cells = if horizontal {
+            track.width
+        } else {
+            track.height
+        };
+        if percentage <= 0.0 || cells == 0 {
+            return;
+        }
+        let cell = ((percentage * cells as f64).ceil() as u16).clamp(1, cells) - 1;
+
+        let symbol = self.peak_symbol.as_deref().unwrap_or(if horizontal {
+            HANDLE_VERTICAL_LINE
+        } else {
+            HANDLE_HORIZONTAL_LINE
+        });
+        let symbol_width = symbol.width() as u16;
+        let handle = self.handle_area(layout);
+        for lane in self.track_lanes(track, self.lane_width()) {
+            let position = if horizontal {
+                Position::new(
+                    lane.x + cell.min(lane.width.saturating_sub(symbol_width)),
+                    lane.y,
+                )
+            } else {
+                Position::new(
+                    lane.x + lane.width.saturating_sub(symbol_width) / 2,
+                    lane.bottom() - 1 - cell,
+                )
+            };
+            if handle.is_some_and(|handle| handle.contains(position)) {
+                continue;
+            }
+            buf.set_string(
+                position.x,
+                position.y,
+                symbol,
+                Style::default().fg(self.peak_color),
+            );
+        }
     }
 
-    fn adjust_for_overlap(
-        &self,
-        area: Rect,
-        label_x: u16,
-        label_w: u16,
-        value_w: u16,
-    ) -> (u16, u16) {
-        match self.value_alignment {
-            Alignment::Center | Alignment::Right => {
-                // Keep label on left, move value to right
-                (label_x, area.x + area.width.saturating_sub(value_w))
+    /// Renders reference markers on the track, leaving the handle uncovered
+    ///
+    /// Labels go above a horizontal track or right of a vertical one, clipped to
+    /// the area. A label overlapping the label of an earlier marker is dropped.
+    fn render_markers(&self, area: Rect, layout: &SliderLayout, buf: &mut Buffer) {
+        let mut placed_labels: Vec<Rect> = Vec::new();
+        let track = layout.track;
+        let lanes = self.track_lanes(track, self.lane_width());
+        let handle = self.handle_area(layout);
+        let under_handle =
+            |x: u16, y: u16| handle.is_some_and(|handle| handle.contains(Position::new(x, y)));
+
+        for marker in &self.markers {
+            if !(self.min..=self.max).contains(&marker.value()) {
+                continue;
             }
-            Alignment::Left => {
-                // Try to add spacing between label and value
-                let spacing = 2;
-                let label_end = label_x + label_w;
+            let style = Style::default().fg(marker.display_color());
+            let symbol_width = marker.symbol().width() as u16;
+
+            match self.orientation {
+                SliderOrientation::Horizontal => {
+                    let cell = tick_cell(marker.value(), self.min, self.max, track.width);
+                    // Keep wide symbols inside the track
+                    let x = track.x + cell.min(track.width.saturating_sub(symbol_width));
+                    for lane in &lanes {
+                        if !under_handle(x, lane.y) {
+                            buf.set_string(x, lane.y, marker.symbol(), style);
+                        }
+                    }
 
-                if label_end + spacing + value_w <= area.x + area.width {
-                    (label_x, label_end + spacing)
-                } else {
-                    // Not enough space, put value on right edge
-                    (label_x, area.x + area.width.saturating_sub(value_w))
+                    if let Some(label) = marker.label_text() {
+                        let label_width = label.width() as u16;
+                        if track.y > area.y && label_width <= area.width {
+                            let label_x = (track.x + cell)
+                                .saturating_sub(label_width / 2)
+                                .clamp(area.x, area.right() - label_width);
+                            let label_area = Rect::new(label_x, track.y - 1, label_width, 1);
+                            if !placed_labels
+                                .iter()
+                                .any(|other| other.intersects(label_area))
+                            {
+                                placed_labels.push(label_area);
+                                buf.set_string(label_x, track.y - 1, label, style);
+                            }
+                        }
+                    }
+                }
+                SliderOrientation::Vertical => {
+                    let cell = tick_cell(marker.value(), self.min, self.max, track.height);
+                    let y = track.bottom().saturating_sub(1) - cell;
+                    for lane in &lanes {
+                        let x = lane.x + lane.width.saturating_sub(symbol_width) / 2;
+                        if !under_handle(x, y) {
+                            buf.set_string(x, y, marker.symbol(), style);
+                        }
+                    }
+
+                    if let Some(label) = marker.label_text() {
+                        let label_x = track.right() + 1;
+                        let label_width = label.width() as u16;
+                        let label_area = Rect::new(label_x, y, label_width, 1);
+                        if label_x + label_width <= area.right()
+                            && !placed_labels
+                                .iter()
+                                .any(|other| other.intersects(label_area))
+                        {
+                            placed_labels.push(label_area);
+                            buf.set_string(label_x, y, label, style);
+                        }
+                    }
                 }
             }
         }
     }
 
-    /// Renders the label text at the specified position
-    fn render_label(&self, buf: &mut Buffer, area: Rect, is_horizontal: bool, label_x: u16) {
-        if let Some(ref label) = self.label {
-            let label_y = if is_horizontal {
-                area.y.saturating_sub(1)
-            } else {
-                area.y
-            };
-
-            if self.is_within_buffer(buf, label_x, label_y) {
-                buf.set_string(label_x, label_y, label, Style::default());
+    /// Returns the part of the area left for the bar once the ruler is reserved
+    ///
+    /// The whole area is returned when it is too small to hold both.
+    fn bar_area_without_ticks(&self, area: Rect, ticks: &Ticks, major: &[(f64, String)]) -> Rect {
+        let before = ticks.side() == TickPlacement::Before;
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                let rows = 1 + u16::from(ticks.has_labels());
+                if area.height <= rows {
+                    return area;
+                }
+                let y = if before { area.y + rows } else { area.y };
+                Rect::new(area.x, y, area.width, area.height - rows)
+            }
+            SliderOrientation::Vertical => {
+                let label_width = major
+                    .iter()
+                    .filter(|_| ticks.has_labels())
+                    .map(|(_, label)| label.width() as u16)
+                    .max()
+                    .unwrap_or(0);
+                let columns = 1 + label_width;
+                if area.width <= columns {
+                    return area;
+                }
+                let x = if before { area.x + columns } else { area.x };
+                Rect::new(x, area.y, area.width - columns, area.height)
             }
         }
     }
 
-    /// Renders the value text at the specified position
-    fn render_value(
+    /// Renders tick marks and labels next to the track, clipped to the area
+    fn render_ticks(
         &self,
-        buf: &mut Buffer,
         area: Rect,
-        is_horizontal: bool,
-        value_x: u16,
-        value_info: Option<(u16, u16, String)>,
+        layout: &SliderLayout,
+        ticks: &Ticks,
+        major: &[(f64, String)],
+        buf: &mut Buffer,
     ) {
-        if let Some((_, _, value_str)) = value_info {
-            let value_y = if is_horizontal {
-                area.y.saturating_sub(1)
+        let track = layout.track;
+        let horizontal = self.orientation.is_horizontal();
+        let before = ticks.side() == TickPlacement::Before;
+        let (major_symbol, minor_symbol) = ticks.symbols(horizontal);
+        let style = Style::default().fg(ticks.mark_color());
+        let minor = ticks.minor_ticks(self.min, self.max, self.step);
+
+        if horizontal {
+            let mark_y = if before {
+                track.y.checked_sub(1)
             } else {
-                area.y + area.height
+                Some(track.bottom())
             };
+            let Some(mark_y) = mark_y.filter(|y| (area.y..area.bottom()).contains(y)) else {
+                return;
+            };
+            let cell = |value| track.x + tick_cell(value, self.min, self.max, track.width);
+            for &value in &minor {
+                buf.set_string(cell(value), mark_y, minor_symbol, style);
+            }
+            for (value, _) in major {
+                buf.set_string(cell(*value), mark_y, major_symbol, style);
+            }
 
-            if self.is_within_buffer(buf, value_x, value_y) {
-                buf.set_string(value_x, value_y, &value_str, Style::default());
+            let label_y = if before {
+                mark_y.checked_sub(1)
+            } else {
+                Some(mark_y + 1)
+            };
+            let Some(label_y) = label_y.filter(|y| (area.y..area.bottom()).contains(y)) else {
+                return;
+            };
+            if !ticks.has_labels() {
+                return;
+            }
+            let labels: Vec<(u16, &str)> = major
+                .iter()
+                .map(|(value, label)| {
+                    let cell = tick_cell(*value, self.min, self.max, track.width);
+                    (cell, label.as_str())
+                })
+                .collect();
+            for (start, index) in place_labels(&labels, track.width) {
+                buf.set_string(track.x + start, label_y, labels[index].1, style);
+            }
+        } else {
+            let mark_x = if before {
+                track.x.checked_sub(1)
+            } else {
+                Some(track.right())
+            };
+            let Some(mark_x) = mark_x.filter(|x| (area.x..area.right()).contains(x)) else {
+                return;
+            };
+            let bottom = track.bottom().saturating_sub(1);
+            let row = |value| bottom - tick_cell(value, self.min, self.max, track.height);
+            for &value in &minor {
+                buf.set_string(mark_x, row(value), minor_symbol, style);
+            }
+            for (value, _) in major {
+                buf.set_string(mark_x, row(*value), major_symbol, style);
             }
-        }
-    }
 
-    fn is_within_buffer(&self, buf: &Buffer, x: u16, y: u16) -> bool {
-        x >= buf.area.x
-            && x < buf.area.x + buf.area.width
-            && y >= buf.area.y
-            && y < buf.area.y + buf.area.height
+            if !ticks.has_labels() {
+                return;
+            }
+            // Ends first; a label is dropped when its row already has one
+            let mut order: Vec<usize> = (0..major.len()).collect();
+            if major.len() > 1 {
+                order.swap(1, major.len() - 1);
+                order[2..].sort_unstable();
+            }
+            let mut used_rows = Vec::new();
+            for index in order {
+                let (value, ref label) = major[index];
+                let y = row(value);
+                let width = label.width() as u16;
+                let x = if before {
+                    match mark_x.checked_sub(width) {
+                        Some(x) if x >= area.x => x,
+                        _ => continue,
+                    }
+                } else if mark_x + 1 + width <= area.right() {
+                    mark_x + 1
+                } else {
+                    continue;
+                };
+                if !used_rows.contains(&y) {
+                    used_rows.push(y);
+                    buf.set_string(x, y, label, style);
+                }
+            }
+        }
     }
 }
 
-impl<'a> Default for Slider<'a> {
-    fn default() -> Self {
-        Self::new(0.0, 0.0, 100.0)
+impl<'a> From<SliderStyle> for Slider<'a> {
+    /// Creates a default slider with the given style applied
+    fn from(style: SliderStyle) -> Self {
+        Self::default().style(style)
     }
 }
 
 impl<'a> Widget for Slider<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let area = match self.block {
-            Some(ref block) => {
-                let inner = block.inner(area);
-                block.clone().render(area, buf);
-                inner
-            }
-            None => area,
-        };
+        self.with_theme_state().render_slider(area, buf);
+    }
+}
 
-        if area.width == 0 || area.height == 0 {
-            return;
+impl<'a> StatefulWidget for Slider<'a> {
+    type State = SliderState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut SliderState) {
+        self.min = state.min();
+        self.max = state.max();
+        self.value = state.value();
+        self.step = state.step();
+        self.editor = state.editor().cloned();
+        if self.value_formatter.is_none() {
+            self.value_formatter = state.value_formatter().cloned();
         }
-
-        // Render label and value if needed
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                self.render_label_and_value(area, buf);
-            }
-            SliderOrientation::Vertical => {
-                self.render_vertical_label_and_value(area, buf);
-            }
+        if self.number_format.is_none() {
+            self.number_format = Some(state.number_format());
         }
 
-        // Render the slider based on orientation
-        match self.orientation {
-            SliderOrientation::Horizontal => self.render_horizontal(area, buf),
-            SliderOrientation::Vertical => self.render_vertical(area, buf),
-        }
+        let layout = self.with_theme_state().render_slider(area, buf);
+        state.set_layout(layout);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gradient::{ColorSupport, GradientSpan};
+    use crate::tick::TickSpacing;
 
     #[test]
     fn test_slider_new() {
@@ -1106,9 +3378,9 @@ mod tests {
             .empty_color(Color::Blue)
             .handle_color(Color::Green);
 
-        assert_eq!(slider.filled_color, Color::Red);
-        assert_eq!(slider.empty_color, Color::Blue);
-        assert_eq!(slider.handle_color, Color::Green);
+        assert_eq!(slider.filled_style.fg, Some(Color::Red));
+        assert_eq!(slider.empty_style.fg, Some(Color::Blue));
+        assert_eq!(slider.handle_style.fg, Some(Color::Green));
     }
 
     #[test]
@@ -1124,54 +3396,288 @@ mod tests {
     }
 
     #[test]
-    fn test_min_max_clamping() {
-        let slider = Slider::default().min(10.0).max(90.0).value(100.0);
-        assert_eq!(slider.value, 90.0);
-
-        let slider = Slider::default().min(10.0).max(90.0).value(5.0);
-        assert_eq!(slider.value, 10.0);
-
-        let slider = Slider::default().min(10.0).max(90.0).value(50.0);
-        assert_eq!(slider.value, 50.0);
+    fn test_min_max_clamping() {
+        let slider = Slider::default().min(10.0).max(90.0).value(100.0);
+        assert_eq!(slider.value, 90.0);
+
+        let slider = Slider::default().min(10.0).max(90.0).value(5.0);
+        assert_eq!(slider.value, 10.0);
+
+        let slider = Slider::default().min(10.0).max(90.0).value(50.0);
+        assert_eq!(slider.value, 50.0);
+    }
+
+    #[test]
+    fn test_label() {
+        let slider = Slider::default().label("Volume");
+        assert_eq!(slider.label, Some("Volume".to_string()));
+
+        let slider = Slider::default();
+        assert_eq!(slider.label, None);
+    }
+
+    #[test]
+    fn test_show_value() {
+        let slider = Slider::default().show_value(true);
+        assert!(slider.show_value);
+
+        let slider = Slider::default();
+        assert!(!slider.show_value);
+    }
+
+    #[test]
+    fn test_orientation() {
+        let slider = Slider::default().orientation(SliderOrientation::Horizontal);
+        assert_eq!(slider.orientation, SliderOrientation::Horizontal);
+
+        let slider = Slider::default().orientation(SliderOrientation::Vertical);
+        assert_eq!(slider.orientation, SliderOrientation::Vertical);
+    }
+
+    #[test]
+    fn test_block() {
+        use ratatui::widgets::{Block, Borders};
+
+        let block = Block::default().borders(Borders::ALL);
+        let slider = Slider::default().block(block);
+        assert!(slider.block.is_some());
+
+        let slider = Slider::default();
+        assert!(slider.block.is_none());
+    }
+
+    #[test]
+    fn test_border_title_follows_value() {
+        use crate::border::{BorderStyle, SliderBlock, TitleAlignment, TitlePosition};
+
+        let border = SliderBlock::new(BorderStyle::Plain)
+            .title("ignored")
+            .title_alignment(TitleAlignment::Right)
+            .title_position(TitlePosition::Bottom);
+        let mut state = SliderState::new(72.0, 0.0, 100.0);
+        let area = Rect::new(0, 0, 16, 3);
+        let mut buf = Buffer::empty(area);
+        let slider = Slider::from_state(&state)
+            .border(border)
+            .border_title("Vol {percent}")
+            .value_formatter(|value: f64| format!("{value:.0} dB"));
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        let top: String = (0..16).map(|x| buf[(x, 0)].symbol()).collect();
+        let bottom: String = (0..16).map(|x| buf[(x, 2)].symbol()).collect();
+        assert_eq!(top, "┌──────────────┐");
+        assert_eq!(bottom, "└───────Vol 72%┘");
+
+        let slider = Slider::new(5.0, 0.0, 10.0)
+            .border(BorderStyle::Plain)
+            .border_title("{value}")
+            .value_formatter(|value: f64| format!("{value:.0} dB"));
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        let top: String = (0..16).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(top, "┌─────5 dB─────┐");
+    }
+
+    #[test]
+    fn test_thick_horizontal_track() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .filled_symbol(FILLED_BLOCK)
+            .empty_symbol("░")
+            .handle_symbol("●")
+            .thickness(3);
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        let area = Rect::new(0, 0, 6, 5);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        let rows: Vec<String> = (0..5)
+            .map(|y| (0..6).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rows, ["      ", "▄▄▄●░░", "███●░░", "▀▀▀●░░", "      "]);
+
+        let layout = state.layout().unwrap();
+        assert_eq!(layout.track, Rect::new(0, 1, 6, 3));
+        assert!(layout.is_over_handle(3, 3));
+    }
+
+    #[test]
+    fn test_thick_vertical_track() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .filled_symbol(FILLED_BLOCK)
+            .empty_symbol("░")
+            .handle_symbol("●")
+            .thickness(2);
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        let area = Rect::new(0, 0, 5, 4);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        let rows: Vec<String> = (0..4)
+            .map(|y| (0..5).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        // Two columns are too few for half-block edges
+        assert_eq!(rows, [" ░░  ", " ●●  ", " ██  ", " ██  "]);
+
+        let layout = state.layout().unwrap();
+        assert_eq!(layout.track, Rect::new(1, 0, 2, 4));
+        assert_eq!(layout.handle, Some(Position::new(1, 1)));
+    }
+
+    #[test]
+    fn test_thickness_clipped_to_area() {
+        let slider = Slider::new(100.0, 0.0, 100.0)
+            .filled_symbol(FILLED_BLOCK)
+            .show_handle(false)
+            .thickness(10);
+        let mut state = SliderState::new(100.0, 0.0, 100.0);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        assert_eq!(state.layout().unwrap().track, area);
+        assert!(area
+            .positions()
+            .all(|position| buf[position].symbol() == "█"));
+    }
+
+    #[test]
+    fn test_label_and_value_stay_inside_area() {
+        let slider = Slider::new(50.0, 0.0, 100.0).label("Vol").show_value(true);
+        let area = Rect::new(0, 1, 12, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), " ".repeat(12));
+        assert_eq!(row_symbols(&buf, 1), "Vol     50.0");
+        assert_eq!(buf[(0, 2)].symbol(), "━");
+        assert_eq!(row_symbols(&buf, 3), " ".repeat(12));
+
+        let slider = Slider::new(5.0, 0.0, 10.0)
+            .orientation(SliderOrientation::Vertical)
+            .label("Lvl")
+            .vertical_label_position(VerticalLabelPosition::Top)
+            .show_value(true)
+            .vertical_value_position(VerticalValuePosition::Bottom);
+        let area = Rect::new(0, 1, 3, 4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 6));
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "   ");
+        assert_eq!(row_symbols(&buf, 1), "Lvl");
+        assert_eq!(row_symbols(&buf, 4).trim(), "5");
+        assert_eq!(row_symbols(&buf, 5), "   ");
+    }
+
+    #[test]
+    fn test_label_dropped_without_room() {
+        let slider = Slider::new(100.0, 0.0, 100.0).label("Vol").show_value(true);
+        let area = Rect::new(0, 1, 6, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Widget::render(slider, area, &mut buf);
+
+        // The track keeps the only row
+        assert_eq!(row_symbols(&buf, 0), "      ");
+        assert_eq!(buf[(0, 1)].symbol(), "━");
     }
 
     #[test]
-    fn test_label() {
-        let slider = Slider::default().label("Volume");
-        assert_eq!(slider.label, Some("Volume".to_string()));
+    fn test_legacy_layout() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .label("Vol")
+            .show_value(true)
+            .legacy_layout(true);
+        let area = Rect::new(0, 1, 12, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        Widget::render(slider, area, &mut buf);
 
-        let slider = Slider::default();
-        assert_eq!(slider.label, None);
+        assert_eq!(row_symbols(&buf, 0), "Vol     50.0");
+        assert_eq!(buf[(0, 1)].symbol(), "━");
     }
 
     #[test]
-    fn test_show_value() {
-        let slider = Slider::default().show_value(true);
-        assert!(slider.show_value);
+    fn test_horizontal_size() {
+        use crate::border::BorderStyle;
+        use crate::tick::Ticks;
 
-        let slider = Slider::default();
-        assert!(!slider.show_value);
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .label("Vol")
+            .show_value(true)
+            .ticks(Ticks::new())
+            .border(BorderStyle::Plain)
+            .thickness(2);
+        // Label row, two track rows, tick marks and labels, plus the border
+        assert_eq!(slider.min_size(), Size::new(12, 7));
+        assert_eq!(slider.preferred_size(), Size::new(22, 7));
+
+        let slider = Slider::new(0.0, -1000.0, 0.0)
+            .show_value(true)
+            .value_placement(ValuePlacement::FollowHandle);
+        // Measured at the widest value, "-1000.0"
+        assert_eq!(slider.min_size(), Size::new(7, 2));
+
+        // Widest inside the range, at "999 Hz", rather than at either end
+        let slider = Slider::new(0.0, 0.0, 2000.0)
+            .show_value(true)
+            .value_formatter(crate::format::SiPrefix::new(0, "Hz"));
+        assert_eq!(slider.min_size(), Size::new(6, 2));
     }
 
     #[test]
-    fn test_orientation() {
-        let slider = Slider::default().orientation(SliderOrientation::Horizontal);
-        assert_eq!(slider.orientation, SliderOrientation::Horizontal);
-
-        let slider = Slider::default().orientation(SliderOrientation::Vertical);
-        assert_eq!(slider.orientation, SliderOrientation::Vertical);
+    fn test_horizontal_size_fits_labels() {
+        use crate::tick::Ticks;
+
+        let slider =
+            Slider::new(50.0, 0.0, 100.0).marker(Marker::new(80.0, "│").label("threshold"));
+        // Marker label row above a one-row track
+        let size = slider.min_size();
+        assert_eq!(size, Size::new(9, 2));
+        let area = Rect::new(0, 0, size.width, size.height);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(row_symbols(&buf, 0), "threshold");
+
+        let slider = Slider::new(0.0, -1000.0, 0.0).ticks(Ticks::new());
+        // Track, tick marks and the "-1000" label
+        let size = slider.min_size();
+        assert_eq!(size, Size::new(5, 3));
+        let area = Rect::new(0, 0, size.width, size.height);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert!(row_symbols(&buf, 2).contains("-1000"));
     }
 
     #[test]
-    fn test_block() {
-        use ratatui::widgets::{Block, Borders};
+    fn test_vertical_size() {
+        let slider = Slider::new(5.0, 0.0, 10.0)
+            .orientation(SliderOrientation::Vertical)
+            .label("Level")
+            .vertical_label_position(VerticalLabelPosition::Top)
+            .show_value(true)
+            .vertical_value_position(VerticalValuePosition::Bottom);
+        assert_eq!(slider.min_size(), Size::new(5, 3));
+        assert_eq!(slider.preferred_size(), Size::new(5, 12));
 
-        let block = Block::default().borders(Borders::ALL);
-        let slider = Slider::default().block(block);
-        assert!(slider.block.is_some());
+        let slider = slider.marker(Marker::new(8.0, "◆").label("max"));
+        // Room for the marker label on either side of the centered track
+        assert_eq!(slider.min_size(), Size::new(8, 3));
+    }
 
-        let slider = Slider::default();
-        assert!(slider.block.is_none());
+    #[test]
+    fn test_border_shrinks_track() {
+        use crate::border::BorderStyle;
+
+        let slider = Slider::new(0.0, 0.0, 100.0)
+            .border(BorderStyle::PlainSidesOnly)
+            .show_handle(false);
+        let mut state = SliderState::new(0.0, 0.0, 100.0);
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        assert_eq!(state.layout().unwrap().track, Rect::new(1, 1, 8, 1));
+        assert_eq!(buf[(0, 0)].symbol(), "│");
+        assert_eq!(buf[(9, 2)].symbol(), "│");
     }
 
     #[test]
@@ -1230,9 +3736,9 @@ mod tests {
         assert_eq!(slider.filled_symbol, "█");
         assert_eq!(slider.empty_symbol, "░");
         assert_eq!(slider.handle_symbol, "▐");
-        assert_eq!(slider.filled_color, Color::Red);
-        assert_eq!(slider.empty_color, Color::Blue);
-        assert_eq!(slider.handle_color, Color::Green);
+        assert_eq!(slider.filled_style.fg, Some(Color::Red));
+        assert_eq!(slider.empty_style.fg, Some(Color::Blue));
+        assert_eq!(slider.handle_style.fg, Some(Color::Green));
         assert!(slider.show_handle);
         assert_eq!(slider.orientation, SliderOrientation::Vertical);
     }
@@ -1314,8 +3820,8 @@ mod tests {
         let mut buf1 = Buffer::empty(area);
         let mut buf2 = Buffer::empty(area);
 
-        slider1.render(area, &mut buf1);
-        slider2.render(area, &mut buf2);
+        Widget::render(slider1, area, &mut buf1);
+        Widget::render(slider2, area, &mut buf2);
 
         // Both should render in the full area height
         // Count non-empty cells to verify rendering happened
@@ -1342,4 +3848,864 @@ mod tests {
             "Both sliders should fill the same height"
         );
     }
+
+    #[test]
+    fn test_from_state_copies_editor() {
+        let mut state = SliderState::new(60.0, 0.0, 100.0);
+        let slider = Slider::from_state(&state);
+        assert!(slider.editor.is_none());
+
+        state.start_editing();
+        let slider = Slider::from_state(&state);
+        assert_eq!(slider.editor.as_ref().map(|e| e.text()), Some("60"));
+    }
+
+    #[test]
+    fn test_edit_field_replaces_value() {
+        let mut state = SliderState::new(60.0, 0.0, 1000.0);
+        state.start_editing();
+        state.editor_mut().unwrap().insert_char('4');
+
+        let slider = Slider::from_state(&state).label("Freq");
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        // The edit field is right-aligned with a trailing cursor cell
+        let row: String = (16..20).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "604 ");
+        assert!(buf[(19, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(!buf[(18, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_edit_field_cursor_inside_text() {
+        let mut state = SliderState::new(75.0, 0.0, 100.0);
+        state.start_editing();
+        state.editor_mut().unwrap().move_home();
+
+        let slider = Slider::from_state(&state).orientation(SliderOrientation::Vertical);
+        let area = Rect::new(0, 0, 4, 6);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(buf[(1, 5)].symbol(), "7");
+        assert!(buf[(1, 5)].modifier.contains(Modifier::REVERSED));
+        assert_eq!(buf[(2, 5)].symbol(), "5");
+    }
+
+    #[test]
+    fn test_from_state_copies_step() {
+        let state = SliderState::with_step(40.0, 0.0, 100.0, 2.5);
+        let slider = Slider::from_state(&state);
+        assert_eq!(slider.step, 2.5);
+    }
+
+    #[test]
+    fn test_stateful_render_reads_live_state() {
+        let mut state = SliderState::new(0.0, 0.0, 10.0);
+        let slider = Slider::default().show_value(true);
+        state.set_value(10.0);
+
+        let area = Rect::new(0, 0, 10, 2);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        // Fully filled bar and the state's value, not the widget's default
+        assert_eq!(buf[(9, 1)].symbol(), "━");
+        let value: String = (6..10).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(value, "10.0");
+    }
+
+    #[test]
+    fn test_stateful_render_records_layout() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        let area = Rect::new(2, 3, 10, 3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        StatefulWidget::render(Slider::default(), area, &mut buf, &mut state);
+
+        let layout = state.layout().unwrap();
+        assert_eq!(layout.track, Rect::new(2, 4, 10, 1));
+        assert_eq!(layout.handle, Some(Position::new(7, 4)));
+        assert_eq!(layout.orientation, SliderOrientation::Horizontal);
+
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        let slider = Slider::default().orientation(SliderOrientation::Vertical);
+        StatefulWidget::render(slider, Rect::new(0, 0, 5, 10), &mut buf, &mut state);
+        let layout = state.layout().unwrap();
+        assert_eq!(layout.track, Rect::new(2, 0, 1, 10));
+        assert_eq!(layout.handle, Some(Position::new(2, 4)));
+    }
+
+    #[test]
+    fn test_stateful_render_empty_area_clears_layout() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(Slider::default(), buf.area, &mut buf, &mut state);
+        assert!(state.layout().is_some());
+
+        StatefulWidget::render(Slider::default(), Rect::default(), &mut buf, &mut state);
+        assert!(state.layout().is_none());
+    }
+
+    #[test]
+    fn test_style_preset() {
+        let preset = SliderStyle::segmented_dots();
+        let slider = Slider::default().style(preset.clone());
+
+        assert_eq!(slider.filled_symbol, preset.filled_symbol);
+        assert_eq!(slider.empty_symbol, preset.empty_symbol);
+        assert_eq!(slider.handle_symbol, preset.handle_symbol);
+        assert_eq!(slider.filled_style.fg, Some(preset.filled_color));
+        assert_eq!(slider.empty_style.fg, Some(preset.empty_color));
+        assert_eq!(slider.handle_style.fg, Some(preset.handle_color));
+        assert!(slider.segmented);
+    }
+
+    #[test]
+    fn test_style_then_override() {
+        let slider = Slider::default()
+            .style(SliderStyle::progress_health())
+            .filled_color(Color::Green);
+        assert_eq!(slider.filled_symbol, "▓");
+        assert_eq!(slider.filled_style.fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_style_with_owned_symbols() {
+        let loaded = String::from(">");
+        let style = SliderStyle::custom(String::from("Loaded")).handle_symbol(loaded);
+        let slider = Slider::default().style(style);
+        assert_eq!(slider.handle_symbol, ">");
+    }
+
+    #[test]
+    fn test_from_style() {
+        let slider: Slider = SliderStyle::vertical_equalizer().into();
+        assert_eq!(slider.filled_symbol, "│");
+        assert_eq!(slider.filled_style.fg, Some(Color::LightGreen));
+        assert!(!slider.segmented);
+        assert_eq!(slider.value, 0.0);
+    }
+
+    fn row_symbols(buf: &Buffer, y: u16) -> String {
+        (buf.area.x..buf.area.x + buf.area.width)
+            .map(|x| buf[(x, y)].symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_segments_layout() {
+        let segments = Segments::new(11, 2, 1);
+        assert_eq!(segments.count, 4);
+        assert_eq!(segments.segment_at(0), Some((0, 0)));
+        assert_eq!(segments.segment_at(2), None);
+        assert_eq!(segments.segment_at(10), Some((3, 1)));
+        assert_eq!(segments.filled(0.5), 2);
+        assert_eq!(segments.handle_offset(0, 1), 0);
+        assert_eq!(segments.handle_offset(2, 1), 5);
+        assert_eq!(segments.handle_offset(4, 1), 10);
+
+        // Leftover cells that cannot hold a whole segment stay blank
+        let segments = Segments::new(10, 2, 1);
+        assert_eq!(segments.count, 3);
+        assert_eq!(segments.segment_at(9), None);
+
+        let segments = Segments::new(1, 2, 1);
+        assert_eq!(segments.count, 1);
+        assert_eq!(segments.segment_at(0), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_render_horizontal_segmented() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .style(SliderStyle::segmented_dots())
+            .handle_symbol("|");
+        let area = Rect::new(0, 0, 11, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "●● ●●|○○ ○○");
+    }
+
+    #[test]
+    fn test_render_horizontal_segmented_custom_pattern() {
+        let slider = Slider::new(40.0, 0.0, 100.0)
+            .segmented(true)
+            .segment_length(1)
+            .segment_gap(1)
+            .filled_symbol("■")
+            .empty_symbol("□")
+            .show_handle(false);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "■ ■ □ □ □ ");
+    }
+
+    #[test]
+    fn test_render_vertical_segmented() {
+        let slider = Slider::new(100.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .segmented(true)
+            .filled_symbol("█")
+            .empty_symbol("░")
+            .handle_symbol("━");
+        let area = Rect::new(0, 0, 1, 5);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let column: String = (0..5).map(|y| buf[(0, y)].symbol()).collect();
+        // Two segments of two rows from the bottom; the handle sits on the top filled cell
+        assert_eq!(column, "━█ ██");
+    }
+
+    #[test]
+    fn test_render_horizontal_eighths() {
+        // 20 cells give 160 levels; 51/160 is 6 full cells and 3 eighths
+        let slider = Slider::new(51.0, 0.0, 160.0)
+            .fill_mode(FillMode::Eighths)
+            .empty_symbol("─")
+            .show_handle(false);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "██████▍─────────────");
+    }
+
+    #[test]
+    fn test_render_horizontal_eighths_resolution() {
+        let area = Rect::new(0, 0, 4, 1);
+        let rendered: Vec<String> = (0..=32)
+            .map(|value| {
+                let slider = Slider::new(value as f64, 0.0, 32.0)
+                    .fill_mode(FillMode::Eighths)
+                    .show_handle(false);
+                let mut buf = Buffer::empty(area);
+                Widget::render(slider, area, &mut buf);
+                row_symbols(&buf, 0)
+            })
+            .collect();
+
+        // Every one of the 33 levels renders differently
+        for pair in rendered.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_render_horizontal_eighths_handle() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .fill_mode(FillMode::Eighths)
+            .handle_symbol("|");
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "██|─");
+    }
+
+    #[test]
+    fn test_render_vertical_eighths() {
+        let slider = Slider::new(21.0, 0.0, 32.0)
+            .orientation(SliderOrientation::Vertical)
+            .fill_mode(FillMode::Eighths)
+            .empty_symbol("│")
+            .show_handle(false);
+        let area = Rect::new(0, 0, 1, 4);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let column: String = (0..4).map(|y| buf[(0, y)].symbol()).collect();
+        assert_eq!(column, "│▅██");
+    }
+
+    #[test]
+    fn test_render_horizontal_braille() {
+        // 5 cells hold 10 dot columns; 30% fills three of them
+        let slider = Slider::new(30.0, 0.0, 100.0)
+            .fill_mode(FillMode::Braille)
+            .show_handle(false);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "⣿⣇⣀⣀⣀");
+    }
+
+    #[test]
+    fn test_render_horizontal_braille_handle() {
+        let slider = Slider::new(30.0, 0.0, 100.0)
+            .fill_mode(FillMode::Braille)
+            .handle_color(Color::Red);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        let mut state = SliderState::new(30.0, 0.0, 100.0);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+
+        // The handle is the single dot column after the fill
+        assert_eq!(row_symbols(&buf, 0), "⣿⣿⣀⣀⣀");
+        assert_eq!(buf[(1, 0)].fg, Color::Red);
+        assert_eq!(state.layout().unwrap().handle, Some(Position::new(1, 0)));
+    }
+
+    #[test]
+    fn test_render_vertical_braille() {
+        // 2 cells hold 8 dot rows; 62.5% fills five of them
+        let slider = Slider::new(62.5, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .fill_mode(FillMode::Braille)
+            .show_handle(false);
+        let area = Rect::new(0, 0, 1, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(buf[(0, 1)].symbol(), "⣿");
+        assert_eq!(buf[(0, 0)].symbol(), "⣇");
+    }
+
+    #[test]
+    fn test_render_gradient_track() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(40, 0, 0)])
+            .color_support(ColorSupport::TrueColor);
+        let slider = Slider::new(60.0, 0.0, 100.0)
+            .gradient(gradient)
+            .show_handle(false);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(20, 0, 0));
+        // Empty cells keep the empty color
+        assert_eq!(buf[(3, 0)].fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_render_gradient_fill() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(40, 0, 0)])
+            .span(GradientSpan::Fill)
+            .color_support(ColorSupport::TrueColor);
+        let slider = Slider::new(60.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .fill_mode(FillMode::Eighths)
+            .gradient(gradient)
+            .show_handle(false);
+        let area = Rect::new(0, 0, 1, 5);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        // The gradient ends at the top of the fill, three rows up
+        assert_eq!(buf[(0, 4)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(0, 2)].fg, Color::Rgb(40, 0, 0));
+    }
+
+    #[test]
+    fn test_render_zones_per_cell() {
+        let slider = Slider::new(100.0, 0.0, 100.0)
+            .filled_symbol("=")
+            .zones([
+                Zone::new(0.0, 60.0, Color::Green),
+                Zone::new(60.0, 100.0, Color::Red).symbol("#"),
+            ])
+            .show_handle(false);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "===##");
+        assert_eq!(buf[(2, 0)].fg, Color::Green);
+        assert_eq!(buf[(3, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_render_zones_whole_fill() {
+        let zones = [
+            Zone::new(0.0, 80.0, Color::Green),
+            Zone::new(80.0, 100.0, Color::Red),
+        ];
+        let area = Rect::new(0, 0, 10, 1);
+
+        let mut buf = Buffer::empty(area);
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .zones(zones.clone())
+            .zone_mode(ZoneMode::WholeFill)
+            .show_handle(false);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Green);
+
+        let mut buf = Buffer::empty(area);
+        let slider = Slider::new(90.0, 0.0, 100.0)
+            .zones(zones)
+            .zone_mode(ZoneMode::WholeFill)
+            .show_handle(false);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(8, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_render_ticks_horizontal() {
+        let slider = Slider::new(0.0, 0.0, 100.0)
+            .empty_symbol("─")
+            .show_handle(false)
+            .ticks(Ticks::new());
+        let area = Rect::new(0, 0, 21, 3);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "─".repeat(21));
+        assert_eq!(row_symbols(&buf, 1), "│    │    │    │    │");
+        assert_eq!(row_symbols(&buf, 2), "0   25   50   75  100");
+    }
+
+    #[test]
+    fn test_render_ticks_before_with_minor() {
+        let slider = Slider::new(0.0, 0.0, 10.0)
+            .empty_symbol("─")
+            .show_handle(false)
+            .ticks(
+                Ticks::new()
+                    .major(TickSpacing::Interval(5.0))
+                    .minor(TickSpacing::Steps(1))
+                    .placement(TickPlacement::Before),
+            );
+        let area = Rect::new(0, 0, 11, 3);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "0    5   10");
+        assert_eq!(row_symbols(&buf, 1), "│╷╷╷╷│╷╷╷╷│");
+        assert_eq!(row_symbols(&buf, 2), "─".repeat(11));
+    }
+
+    #[test]
+    fn test_render_ticks_labels_never_overlap() {
+        let slider = Slider::new(0.0, 0.0, 100.0)
+            .show_handle(false)
+            .ticks(Ticks::new().major(TickSpacing::Interval(10.0)));
+        let area = Rect::new(0, 0, 12, 3);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let labels = row_symbols(&buf, 2);
+        assert!(labels.starts_with('0'));
+        assert!(labels.ends_with("100"));
+        // Every label is separated from the next by at least one blank
+        for word in labels.split_whitespace() {
+            assert!(word.parse::<f64>().is_ok(), "garbled label {word:?}");
+        }
+    }
+
+    #[test]
+    fn test_render_ticks_vertical() {
+        let slider = Slider::new(0.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .empty_symbol("│")
+            .show_handle(false)
+            .ticks(Ticks::new().major(TickSpacing::Interval(50.0)));
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let rows: Vec<String> = (0..5).map(|y| row_symbols(&buf, y)).collect();
+        assert_eq!(rows[0], "│─100");
+        assert_eq!(rows[2], "│─50 ");
+        assert_eq!(rows[4], "│─0  ");
+    }
+
+    #[test]
+    fn test_render_markers_horizontal() {
+        let slider = Slider::new(20.0, 0.0, 100.0)
+            .filled_symbol("=")
+            .empty_symbol("-")
+            .handle_symbol("O")
+            .horizontal_bar_alignment(HorizontalBarAlignment::Bottom)
+            .markers([
+                Marker::new(50.0, "|").label("target"),
+                Marker::new(20.0, "x").label("saved"),
+            ]);
+        let area = Rect::new(0, 0, 11, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        // The handle stays on top of the marker at the same value
+        assert_eq!(row_symbols(&buf, 1), "==O--|-----");
+        // Labels that would overlap an earlier one are dropped
+        assert_eq!(row_symbols(&buf, 0), "  target   ");
+    }
+
+    #[test]
+    fn test_render_markers_vertical() {
+        let slider = Slider::new(0.0, 0.0, 4.0)
+            .orientation(SliderOrientation::Vertical)
+            .empty_symbol("│")
+            .show_handle(false)
+            .marker(Marker::new(3.0, "◆").color(Color::Green).label("hi"));
+        let area = Rect::new(0, 0, 7, 5);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 1), "   ◆ hi");
+        assert_eq!(buf[(3, 1)].fg, Color::Green);
+    }
+
+    #[test]
+    fn test_render_peak_horizontal() {
+        let slider = Slider::new(30.0, 0.0, 100.0)
+            .filled_symbol("=")
+            .empty_symbol("-")
+            .show_handle(false)
+            .peak(75.0);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "===----│--");
+        assert_eq!(buf[(7, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_render_peak_from_meter() {
+        let mut meter = MeterState::new(0.0, 4.0);
+        meter.set_value(4.0);
+        meter.set_value(1.0);
+
+        let slider = Slider::from_meter(&meter)
+            .orientation(SliderOrientation::Vertical)
+            .filled_symbol("█")
+            .empty_symbol("│")
+            .show_handle(false);
+        let area = Rect::new(0, 0, 1, 4);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let column: String = (0..4).map(|y| buf[(0, y)].symbol()).collect();
+        assert_eq!(column, "━││█");
+    }
+
+    #[test]
+    fn test_render_secondary_horizontal() {
+        let slider = Slider::new(30.0, 0.0, 100.0)
+            .filled_symbol("=")
+            .empty_symbol("-")
+            .handle_symbol("O")
+            .secondary_value(70.0)
+            .secondary_symbol("~")
+            .secondary_color(Color::Blue);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "===O~~~---");
+        assert_eq!(buf[(5, 0)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn test_render_secondary_behind_value_is_hidden() {
+        let slider = Slider::new(70.0, 0.0, 100.0)
+            .filled_symbol("=")
+            .empty_symbol("-")
+            .show_handle(false)
+            .secondary_value(30.0)
+            .secondary_symbol("~");
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "=======---");
+    }
+
+    #[test]
+    fn test_render_secondary_vertical() {
+        let slider = Slider::new(25.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .filled_symbol("█")
+            .empty_symbol("│")
+            .show_handle(false)
+            .secondary_value(75.0)
+            .secondary_symbol("▒");
+        let area = Rect::new(0, 0, 1, 4);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        let column: String = (0..4).map(|y| buf[(0, y)].symbol()).collect();
+        assert_eq!(column, "│▒▒█");
+    }
+
+    #[test]
+    fn test_render_secondary_braille() {
+        let slider = Slider::new(10.0, 0.0, 100.0)
+            .fill_mode(FillMode::Braille)
+            .show_handle(false)
+            .secondary_value(60.0)
+            .secondary_color(Color::Blue);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "⣿⣿⣿⣀⣀");
+        assert_eq!(buf[(0, 0)].fg, Color::Cyan);
+        assert_eq!(buf[(2, 0)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn test_value_follows_handle_horizontal() {
+        let area = Rect::new(0, 0, 20, 3);
+        let render = |value: f64| {
+            let slider = Slider::new(value, 0.0, 100.0)
+                .show_value(true)
+                .value_placement(ValuePlacement::FollowHandle);
+            let mut buf = Buffer::empty(area);
+            Widget::render(slider, area, &mut buf);
+            row_symbols(&buf, 0)
+        };
+
+        // Centered above the handle at column 10
+        assert_eq!(render(50.0), "        50.0        ");
+        // Clamped at both edges
+        assert_eq!(render(0.0), "0.0                 ");
+        assert_eq!(render(100.0), "               100.0");
+    }
+
+    #[test]
+    fn test_value_follows_handle_avoids_label() {
+        // The bar is on the first row, so the value shares the row with the label
+        let slider = Slider::new(10.0, 0.0, 100.0)
+            .label("Volume")
+            .show_value(true)
+            .horizontal_bar_alignment(HorizontalBarAlignment::Top)
+            .value_placement(ValuePlacement::FollowHandle);
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 0), "Volume 10.0         ");
+    }
+
+    #[test]
+    fn test_value_follows_handle_vertical() {
+        let slider = Slider::new(75.0, 0.0, 100.0)
+            .orientation(SliderOrientation::Vertical)
+            .show_value(true)
+            .value_placement(ValuePlacement::FollowHandle);
+        let area = Rect::new(0, 0, 7, 5);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(row_symbols(&buf, 1), "   ● 75");
+        // Nothing at the fixed bottom position
+        assert_eq!(row_symbols(&buf, 4), "   ━   ");
+    }
+
+    #[test]
+    fn test_value_formatter() {
+        let slider = Slider::new(-6.0, -60.0, 0.0)
+            .show_value(true)
+            .value_formatter(crate::format::Unit::new(1, " dB"));
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert!(row_symbols(&buf, 0).ends_with("-6.0 dB"));
+    }
+
+    #[test]
+    fn test_non_ascii_value_right_aligned() {
+        let slider = Slider::new(5.0, 0.0, 10.0)
+            .show_value(true)
+            .value_alignment(Alignment::Right)
+            .value_formatter(crate::format::Unit::new(0, "µV"));
+        let area = Rect::new(0, 0, 12, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(row_symbols(&buf, 0), "         5µV");
+
+        let slider = Slider::new(5.0, 0.0, 10.0)
+            .orientation(SliderOrientation::Vertical)
+            .show_value(true)
+            .vertical_value_position(VerticalValuePosition::Top)
+            .vertical_value_alignment(VerticalValueAlignment::Right)
+            .value_formatter(crate::format::Unit::new(0, "°C"));
+        let area = Rect::new(0, 0, 6, 4);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(row_symbols(&buf, 0), "   5°C");
+    }
+
+    #[test]
+    fn test_value_formatter_from_state() {
+        let mut state = SliderState::new(195.0, 0.0, 240.0);
+        state.set_value_formatter(crate::format::Clock::new());
+        let area = Rect::new(0, 0, 20, 2);
+
+        let mut buf = Buffer::empty(area);
+        Widget::render(Slider::from_state(&state).show_value(true), area, &mut buf);
+        assert!(row_symbols(&buf, 0).ends_with("03:15"));
+
+        // Rendering from the state uses its formatter unless the slider has one
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(
+            Slider::default().show_value(true),
+            area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(row_symbols(&buf, 0).ends_with("03:15"));
+
+        let slider = Slider::default()
+            .show_value(true)
+            .value_formatter(crate::format::Percent::new(0));
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(slider, area, &mut buf, &mut state);
+        assert!(row_symbols(&buf, 0).ends_with("81%"));
+    }
+
+    #[test]
+    fn test_number_format() {
+        let format = NumberFormat::new()
+            .decimal_separator(',')
+            .grouping_separator(' ');
+        let slider = Slider::new(12345.5, 0.0, 20000.0)
+            .show_value(true)
+            .number_format(format);
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert!(row_symbols(&buf, 0).ends_with("12 345,5"));
+
+        // Tick labels use the number format too
+        let slider = Slider::new(12345.5, 0.0, 20000.0)
+            .number_format(format)
+            .ticks(Ticks::new().major(TickSpacing::Interval(10000.0)));
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+        assert_eq!(row_symbols(&buf, 2), "0      10 000 20 000");
+    }
+
+    #[test]
+    fn test_number_format_from_state() {
+        let mut state = SliderState::new(2.5, 0.0, 10.0);
+        state.set_number_format(NumberFormat::new().decimal_separator(','));
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(
+            Slider::default().show_value(true),
+            area,
+            &mut buf,
+            &mut state,
+        );
+
+        assert!(row_symbols(&buf, 0).ends_with("2,5"));
+    }
+
+    #[test]
+    fn test_styles() {
+        let slider = Slider::new(50.0, 0.0, 100.0)
+            .label("Vol")
+            .show_value(true)
+            .label_style(Style::new().add_modifier(Modifier::BOLD))
+            .value_style(Style::new().fg(Color::Yellow))
+            .filled_style(Style::new().fg(Color::Red).bg(Color::Black))
+            .empty_style(Style::new().add_modifier(Modifier::DIM))
+            .handle_style(Style::new().add_modifier(Modifier::REVERSED))
+            .handle_color(Color::Green);
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+        // A surrounding background the slider should keep
+        buf.set_style(buf.area, Style::new().bg(Color::Blue));
+        Widget::render(slider, area, &mut buf);
+
+        assert!(buf[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buf[(9, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(9, 0)].bg, Color::Blue);
+
+        let bar_y = (1..3).find(|&y| buf[(0, y)].symbol() == "━").unwrap();
+        assert_eq!(buf[(0, bar_y)].fg, Color::Red);
+        assert_eq!(buf[(0, bar_y)].bg, Color::Black);
+        let handle = buf[(5, bar_y)].clone();
+        assert_eq!(handle.fg, Color::Green);
+        assert!(handle.modifier.contains(Modifier::REVERSED));
+        let empty = &buf[(9, bar_y)];
+        assert!(empty.modifier.contains(Modifier::DIM));
+        assert_eq!(empty.bg, Color::Blue);
+    }
+
+    #[test]
+    fn test_zone_keeps_filled_style() {
+        let slider = Slider::new(100.0, 0.0, 100.0)
+            .show_handle(false)
+            .filled_style(Style::new().add_modifier(Modifier::BOLD))
+            .zones([Zone::new(0.0, 100.0, Color::Magenta)]);
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        Widget::render(slider, area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].fg, Color::Magenta);
+        assert!(buf[(0, 0)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_theme_states() {
+        let theme = SliderTheme::dark();
+        let area = Rect::new(0, 0, 10, 1);
+        let render = |slider: Slider| {
+            let mut buf = Buffer::empty(area);
+            Widget::render(slider, area, &mut buf);
+            buf
+        };
+
+        let normal = render(Slider::new(50.0, 0.0, 100.0).theme(&theme));
+        assert_eq!(normal[(0, 0)].fg, Color::Cyan);
+        assert_eq!(normal[(5, 0)].fg, Color::White);
+
+        // Order of the builder calls does not matter
+        let focused = render(Slider::new(50.0, 0.0, 100.0).focused(true).theme(&theme));
+        assert_eq!(focused[(0, 0)].fg, Color::LightCyan);
+        assert_eq!(focused[(5, 0)].fg, Color::Yellow);
+        assert_eq!(focused[(9, 0)].fg, Color::DarkGray);
+
+        let disabled = render(
+            Slider::new(50.0, 0.0, 100.0)
+                .theme(&theme)
+                .focused(true)
+                .disabled(true),
+        );
+        assert_eq!(disabled[(5, 0)].fg, Color::DarkGray);
+        assert!(disabled[(0, 0)].modifier.contains(Modifier::DIM));
+
+        // Overrides after the theme stick in the normal look
+        let overridden = render(
+            Slider::new(50.0, 0.0, 100.0)
+                .theme(&theme)
+                .filled_color(Color::Green),
+        );
+        assert_eq!(overridden[(0, 0)].fg, Color::Green);
+
+        // ... and in the focused look, where only unset fields follow the theme
+        let focused = render(
+            Slider::new(50.0, 0.0, 100.0)
+                .theme(&theme)
+                .filled_color(Color::Green)
+                .focused(true),
+        );
+        assert_eq!(focused[(0, 0)].fg, Color::Green);
+        assert_eq!(focused[(5, 0)].fg, Color::Yellow);
+
+        // Overrides before the theme are replaced by it
+        let replaced = render(
+            Slider::new(50.0, 0.0, 100.0)
+                .filled_color(Color::Green)
+                .theme(&theme)
+                .focused(true),
+        );
+        assert_eq!(replaced[(0, 0)].fg, Color::LightCyan);
+
+        // Without a theme the flags change nothing
+        let plain = render(Slider::new(50.0, 0.0, 100.0).disabled(true));
+        assert_eq!(plain[(0, 0)].fg, Color::Cyan);
+    }
 }
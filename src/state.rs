@@ -51,6 +51,57 @@
 //! state.set_percentage(0.75);
 //! assert_eq!(state.value(), 75.0);
 //! ```
+//!
+//! ## Typing Exact Values
+//!
+//! ```
+//! use tui_slider::SliderState;
+//!
+//! let mut state = SliderState::new(50.0, 0.0, 1000.0);
+//!
+//! // Open the edit field, replace its contents and commit
+//! state.start_editing();
+//! let editor = state.editor_mut().unwrap();
+//! editor.clear();
+//! for c in "440".chars() {
+//!     editor.insert_char(c);
+//! }
+//! assert_eq!(state.commit_edit(), Ok(440.0));
+//! assert_eq!(state.value(), 440.0);
+//! ```
+//!
+//! ## Handling Input
+//!
+//! ```
+//! use tui_slider::input::SliderKey;
+//! use tui_slider::SliderState;
+//!
+//! let mut state = SliderState::with_step(50.0, 0.0, 100.0, 5.0);
+//!
+//! // Events from any backend can be passed once converted to `SliderInput`
+//! assert!(state.handle_input(SliderKey::Right));
+//! assert_eq!(state.value(), 55.0);
+//!
+//! state.handle_input(SliderKey::End);
+//! assert_eq!(state.value(), 100.0);
+//! ```
+
+use crate::{
+    format::{Decimals, FormatContext, NumberFormat, Percent, SharedFormatter, ValueFormatter},
+    input::{SliderInput, SliderKey, SliderMouse, SliderMouseKind},
+    orientation::SliderOrientation,
+};
+use ratatui::layout::{Position, Rect};
+use std::fmt;
+
+/// Number of steps moved by page up / page down
+const PAGE_STEPS: f64 = 10.0;
+
+/// Default fraction of the range below which a value is low
+const DEFAULT_LOW_THRESHOLD: f64 = 0.33;
+
+/// Default fraction of the range from which a value is high
+const DEFAULT_HIGH_THRESHOLD: f64 = 0.67;
 
 /// State management for a slider widget
 ///
@@ -81,6 +132,20 @@ pub struct SliderState {
     max: f64,
     /// Step size for increment/decrement operations
     step: f64,
+    /// Text field used while a value is being typed in
+    editor: Option<ValueEditor>,
+    /// Where the slider was last rendered, for hit-testing
+    layout: Option<SliderLayout>,
+    /// Whether a press on the track is being dragged
+    dragging: bool,
+    /// Fraction of the range below which the value is low
+    low_threshold: f64,
+    /// Fraction of the range from which the value is high
+    high_threshold: f64,
+    /// Formatter for the displayed value
+    value_formatter: Option<SharedFormatter>,
+    /// How numbers are written
+    number_format: NumberFormat,
 }
 
 impl SliderState {
@@ -121,6 +186,13 @@ impl SliderState {
             min,
             max,
             step: 1.0, // Default step size
+            editor: None,
+            layout: None,
+            dragging: false,
+            low_threshold: DEFAULT_LOW_THRESHOLD,
+            high_threshold: DEFAULT_HIGH_THRESHOLD,
+            value_formatter: None,
+            number_format: NumberFormat::new(),
         }
     }
 
@@ -431,6 +503,13 @@ impl SliderState {
             min,
             max,
             step,
+            editor: None,
+            layout: None,
+            dragging: false,
+            low_threshold: DEFAULT_LOW_THRESHOLD,
+            high_threshold: DEFAULT_HIGH_THRESHOLD,
+            value_formatter: None,
+            number_format: NumberFormat::new(),
         }
     }
 
@@ -544,7 +623,10 @@ impl SliderState {
         (self.value - middle).abs() < self.range() * 0.1
     }
 
-    /// Returns true if the slider value is in the lower third of its range
+    /// Returns true if the slider value is below the low threshold
+    ///
+    /// The threshold defaults to the lower third of the range; see
+    /// [`set_thresholds`](Self::set_thresholds).
     ///
     /// # Examples
     ///
@@ -558,10 +640,13 @@ impl SliderState {
     /// assert!(!state.is_low());
     /// ```
     pub fn is_low(&self) -> bool {
-        self.percentage() < 0.33
+        self.percentage() < self.low_threshold
     }
 
-    /// Returns true if the slider value is in the middle third of its range
+    /// Returns true if the slider value is between the low and high thresholds
+    ///
+    /// The thresholds default to the middle third of the range; see
+    /// [`set_thresholds`](Self::set_thresholds).
     ///
     /// # Examples
     ///
@@ -576,10 +661,13 @@ impl SliderState {
     /// ```
     pub fn is_medium(&self) -> bool {
         let pct = self.percentage();
-        (0.33..0.67).contains(&pct)
+        (self.low_threshold..self.high_threshold).contains(&pct)
     }
 
-    /// Returns true if the slider value is in the upper third of its range
+    /// Returns true if the slider value is at or above the high threshold
+    ///
+    /// The threshold defaults to the upper third of the range; see
+    /// [`set_thresholds`](Self::set_thresholds).
     ///
     /// # Examples
     ///
@@ -593,7 +681,85 @@ impl SliderState {
     /// assert!(!state.is_high());
     /// ```
     pub fn is_high(&self) -> bool {
-        self.percentage() >= 0.67
+        self.percentage() >= self.high_threshold
+    }
+
+    /// Gets the low and high thresholds as fractions of the range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let state = SliderState::new(50.0, 0.0, 100.0);
+    /// assert_eq!(state.thresholds(), (0.33, 0.67));
+    /// ```
+    pub fn thresholds(&self) -> (f64, f64) {
+        (self.low_threshold, self.high_threshold)
+    }
+
+    /// Sets the thresholds used by [`is_low`](Self::is_low),
+    /// [`is_medium`](Self::is_medium) and [`is_high`](Self::is_high)
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - Fraction of the range below which the value is low
+    /// * `high` - Fraction of the range from which the value is high
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0.0 <= low <= high <= 1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// // CPU usage is only high above 80%
+    /// let mut state = SliderState::new(75.0, 0.0, 100.0);
+    /// state.set_thresholds(0.5, 0.8);
+    /// assert!(state.is_medium());
+    ///
+    /// state.set_value(85.0);
+    /// assert!(state.is_high());
+    /// ```
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        assert!(
+            (0.0..=1.0).contains(&low) && (0.0..=1.0).contains(&high) && low <= high,
+            "thresholds must satisfy 0.0 <= low <= high <= 1.0"
+        );
+        self.low_threshold = low;
+        self.high_threshold = high;
+    }
+
+    /// Returns the value at the low threshold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 200.0);
+    /// state.set_thresholds(0.25, 0.75);
+    /// assert_eq!(state.low_threshold_value(), 50.0);
+    /// ```
+    pub fn low_threshold_value(&self) -> f64 {
+        self.min + self.range() * self.low_threshold
+    }
+
+    /// Returns the value at the high threshold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 200.0);
+    /// state.set_thresholds(0.25, 0.75);
+    /// assert_eq!(state.high_threshold_value(), 150.0);
+    /// ```
+    pub fn high_threshold_value(&self) -> f64 {
+        self.min + self.range() * self.high_threshold
     }
 
     /// Returns the distance from the minimum value
@@ -642,7 +808,7 @@ impl SliderState {
     /// assert_eq!(state.value_string(0), "76");
     /// ```
     pub fn value_string(&self, decimals: usize) -> String {
-        format!("{:.decimals$}", self.value, decimals = decimals)
+        Decimals::new(decimals).format(self.value, &self.format_context())
     }
 
     /// Returns a formatted percentage string (e.g., "75%")
@@ -659,7 +825,511 @@ impl SliderState {
     /// assert_eq!(state.percentage_string(), "50%");
     /// ```
     pub fn percentage_string(&self) -> String {
-        format!("{:.0}%", self.percentage() * 100.0)
+        Percent::new(0).format(self.value, &self.format_context())
+    }
+
+    /// Sets the formatter for the displayed value
+    ///
+    /// Sliders created with [`Slider::from_state`](crate::Slider::from_state) or
+    /// rendered from the state use it for their value display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::format::Unit;
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(-6.0, -60.0, 0.0);
+    /// state.set_value_formatter(Unit::new(1, " dB"));
+    /// assert_eq!(state.formatted_value(), "-6.0 dB");
+    /// ```
+    pub fn set_value_formatter<F: ValueFormatter + 'static>(&mut self, formatter: F) {
+        self.value_formatter = Some(SharedFormatter::new(formatter));
+    }
+
+    /// Returns the value as text using the configured formatter
+    ///
+    /// Without a formatter the value is printed with one decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::format::Percent;
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(72.0, 0.0, 100.0);
+    /// assert_eq!(state.formatted_value(), "72.0");
+    ///
+    /// state.set_value_formatter(Percent::new(0));
+    /// assert_eq!(state.formatted_value(), "72%");
+    /// ```
+    pub fn formatted_value(&self) -> String {
+        match &self.value_formatter {
+            Some(formatter) => formatter.format(self.value, &self.format_context()),
+            None => self.value_string(1),
+        }
+    }
+
+    /// Sets how numbers are written, such as the decimal separator
+    ///
+    /// Used by [`value_string`](Self::value_string),
+    /// [`percentage_string`](Self::percentage_string), the value formatter and
+    /// sliders rendered from the state. The edit field is pre-filled with `,` as
+    /// the decimal separator when the format uses it, and typed values are read
+    /// with the same separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::format::NumberFormat;
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(12.5, 0.0, 100.0);
+    /// state.set_number_format(NumberFormat::new().decimal_separator(','));
+    /// assert_eq!(state.value_string(1), "12,5");
+    /// ```
+    pub fn set_number_format(&mut self, number_format: NumberFormat) {
+        self.number_format = number_format;
+    }
+
+    /// Gets how numbers are written
+    pub fn number_format(&self) -> NumberFormat {
+        self.number_format
+    }
+
+    /// Returns the configured value formatter, if any
+    pub(crate) fn value_formatter(&self) -> Option<&SharedFormatter> {
+        self.value_formatter.as_ref()
+    }
+
+    /// Returns the range the value is formatted in
+    fn format_context(&self) -> FormatContext {
+        FormatContext::new(self.min, self.max).number_format(self.number_format)
+    }
+
+    /// Opens the edit field, pre-filled with the current value
+    ///
+    /// The value is printed with as many decimals as the step size uses and the
+    /// cursor is placed at the end. Calling this while already editing keeps the
+    /// current text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::with_step(2.5, 0.0, 10.0, 0.5);
+    /// state.start_editing();
+    /// assert!(state.is_editing());
+    /// assert_eq!(state.editor().unwrap().text(), "2.5");
+    /// ```
+    pub fn start_editing(&mut self) {
+        if self.editor.is_none() {
+            let text = self
+                .number_format
+                .format_editable(self.value, self.step_decimals());
+            self.editor = Some(ValueEditor::new(text));
+        }
+    }
+
+    /// Returns true if the value is currently being typed in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 100.0);
+    /// assert!(!state.is_editing());
+    ///
+    /// state.start_editing();
+    /// assert!(state.is_editing());
+    /// ```
+    pub fn is_editing(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Gets the edit field, if editing
+    pub fn editor(&self) -> Option<&ValueEditor> {
+        self.editor.as_ref()
+    }
+
+    /// Gets mutable access to the edit field, if editing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(5.0, 0.0, 100.0);
+    /// state.start_editing();
+    ///
+    /// if let Some(editor) = state.editor_mut() {
+    ///     editor.insert_char('0');
+    /// }
+    /// assert_eq!(state.editor().unwrap().text(), "50");
+    /// ```
+    pub fn editor_mut(&mut self) -> Option<&mut ValueEditor> {
+        self.editor.as_mut()
+    }
+
+    /// Checks the typed text without applying it
+    ///
+    /// Returns the value that [`commit_edit`](Self::commit_edit) would apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::state::{EditError, SliderState};
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 100.0);
+    /// assert_eq!(state.validate_edit(), Err(EditError::NotEditing));
+    ///
+    /// state.start_editing();
+    /// state.editor_mut().unwrap().insert_char('0');
+    /// assert_eq!(
+    ///     state.validate_edit(),
+    ///     Err(EditError::OutOfRange { min: 0.0, max: 100.0 })
+    /// );
+    /// ```
+    pub fn validate_edit(&self) -> Result<f64, EditError> {
+        let editor = self.editor.as_ref().ok_or(EditError::NotEditing)?;
+        let text = editor.text().trim();
+        if text.is_empty() {
+            return Err(EditError::Empty);
+        }
+
+        let value = self
+            .number_format
+            .parse_editable(text)
+            .ok_or(EditError::Invalid)?;
+        if !value.is_finite() {
+            return Err(EditError::Invalid);
+        }
+        if value < self.min || value > self.max {
+            return Err(EditError::OutOfRange {
+                min: self.min,
+                max: self.max,
+            });
+        }
+
+        Ok(self.snap_to_step(value))
+    }
+
+    /// Applies the typed value and closes the edit field
+    ///
+    /// The text is parsed with the state's [`NumberFormat`]: its decimal
+    /// separator (`.` if it uses one that cannot be typed) and, between full
+    /// digit groups, its grouping separator. Any other `.` or `,` is rejected as
+    /// [`EditError::Invalid`] rather than guessed at. The value is then checked
+    /// against the min/max bounds and snapped to the nearest step counted from
+    /// the minimum, or to the maximum when that is nearer. On error the edit
+    /// field stays open so the text can be corrected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::state::{EditError, SliderState};
+    ///
+    /// let mut state = SliderState::with_step(0.0, 0.0, 100.0, 5.0);
+    /// state.start_editing();
+    /// let editor = state.editor_mut().unwrap();
+    /// editor.clear();
+    /// editor.insert_char('4');
+    /// editor.insert_char('2');
+    ///
+    /// // Snapped to the step grid
+    /// assert_eq!(state.commit_edit(), Ok(40.0));
+    /// assert!(!state.is_editing());
+    ///
+    /// state.start_editing();
+    /// state.editor_mut().unwrap().clear();
+    /// assert_eq!(state.commit_edit(), Err(EditError::Empty));
+    /// assert!(state.is_editing());
+    /// ```
+    pub fn commit_edit(&mut self) -> Result<f64, EditError> {
+        let value = self.validate_edit()?;
+        self.set_value(value);
+        self.editor = None;
+        Ok(self.value)
+    }
+
+    /// Closes the edit field without changing the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 100.0);
+    /// state.start_editing();
+    /// state.editor_mut().unwrap().insert_char('5');
+    /// state.cancel_edit();
+    ///
+    /// assert!(!state.is_editing());
+    /// assert_eq!(state.value(), 50.0);
+    /// ```
+    pub fn cancel_edit(&mut self) {
+        self.editor = None;
+    }
+
+    /// Handles a keyboard or mouse event
+    ///
+    /// Accepts anything convertible to [`SliderInput`], so events from crossterm,
+    /// termion or termwiz can be passed directly when the matching feature is
+    /// enabled. Returns true if the value or the edit field changed.
+    ///
+    /// Key bindings:
+    ///
+    /// - `Right`/`Up` and `Left`/`Down` - step up / down
+    /// - `PageUp`/`PageDown` - move ten steps
+    /// - `Home`/`End` - jump to the minimum / maximum
+    /// - `Enter` - open the edit field; while editing, commit the typed value
+    /// - `Esc` - cancel editing
+    ///
+    /// While editing, characters, `Backspace`, `Delete`, `Left`, `Right`, `Home`
+    /// and `End` go to the edit field. Mouse events are hit-tested against the
+    /// layout recorded by the last stateful render (see [`layout`](Self::layout))
+    /// and ignored if the slider has not been rendered yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_slider::input::SliderKey;
+    /// use tui_slider::SliderState;
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 100.0);
+    ///
+    /// state.handle_input(SliderKey::PageUp);
+    /// assert_eq!(state.value(), 60.0);
+    ///
+    /// // Type an exact value
+    /// state.handle_input(SliderKey::Enter);
+    /// state.handle_input(SliderKey::Backspace);
+    /// state.handle_input(SliderKey::Backspace);
+    /// state.handle_input(SliderKey::Char('7'));
+    /// state.handle_input(SliderKey::Enter);
+    /// assert_eq!(state.value(), 7.0);
+    /// ```
+    pub fn handle_input<I: Into<SliderInput>>(&mut self, input: I) -> bool {
+        match input.into() {
+            SliderInput::Key(key) => self.handle_key(key),
+            SliderInput::Mouse(mouse) => match self.layout {
+                Some(layout) => self.handle_mouse(mouse, layout.track, layout.orientation),
+                None => false,
+            },
+            SliderInput::Unsupported => false,
+        }
+    }
+
+    /// Handles a key press
+    ///
+    /// See [`handle_input`](Self::handle_input) for the key bindings.
+    pub fn handle_key(&mut self, key: SliderKey) -> bool {
+        if let Some(ref mut editor) = self.editor {
+            return match key {
+                SliderKey::Char(c) => editor.insert_char(c),
+                SliderKey::Backspace => editor.delete_backward(),
+                SliderKey::Delete => editor.delete_forward(),
+                SliderKey::Left => {
+                    editor.move_left();
+                    true
+                }
+                SliderKey::Right => {
+                    editor.move_right();
+                    true
+                }
+                SliderKey::Home => {
+                    editor.move_home();
+                    true
+                }
+                SliderKey::End => {
+                    editor.move_end();
+                    true
+                }
+                SliderKey::Enter => self.commit_edit().is_ok(),
+                SliderKey::Esc => {
+                    self.cancel_edit();
+                    true
+                }
+                SliderKey::Up | SliderKey::Down | SliderKey::PageUp | SliderKey::PageDown => false,
+            };
+        }
+
+        let previous = self.value;
+        match key {
+            SliderKey::Right | SliderKey::Up => self.step_up(),
+            SliderKey::Left | SliderKey::Down => self.step_down(),
+            SliderKey::PageUp => self.increase(self.step * PAGE_STEPS),
+            SliderKey::PageDown => self.decrease(self.step * PAGE_STEPS),
+            SliderKey::Home => self.set_value(self.min),
+            SliderKey::End => self.set_value(self.max),
+            SliderKey::Enter => {
+                self.start_editing();
+                return true;
+            }
+            SliderKey::Esc | SliderKey::Backspace | SliderKey::Delete | SliderKey::Char(_) => {
+                return false
+            }
+        }
+        self.value != previous
+    }
+
+    /// Handles a mouse event over a slider track
+    ///
+    /// Pressing inside `track` jumps to the clicked position and starts a drag.
+    /// While the drag lasts, the value follows the pointer (even outside the
+    /// track); it ends on release or a move without the button held. Drags that
+    /// began elsewhere are ignored, so one drag never moves several sliders. The
+    /// wheel steps the value while over the track. Positions are snapped to the
+    /// step size. Returns true if the value changed.
+    ///
+    /// A press while already dragging is treated as a drag, for backends that
+    /// report a held button as repeated presses.
+    ///
+    /// # Arguments
+    ///
+    /// * `mouse` - The mouse event
+    /// * `track` - Area the slider bar was rendered into
+    /// * `orientation` - Orientation the slider was rendered with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tui_slider::input::{SliderMouse, SliderMouseKind};
+    /// use tui_slider::{SliderOrientation, SliderState};
+    ///
+    /// let mut state = SliderState::new(0.0, 0.0, 100.0);
+    /// let track = Rect::new(10, 5, 11, 1);
+    ///
+    /// let click = SliderMouse::new(SliderMouseKind::Down, 15, 5);
+    /// assert!(state.handle_mouse(click, track, SliderOrientation::Horizontal));
+    /// assert_eq!(state.value(), 50.0);
+    /// ```
+    pub fn handle_mouse(
+        &mut self,
+        mouse: SliderMouse,
+        track: Rect,
+        orientation: SliderOrientation,
+    ) -> bool {
+        if self.editor.is_some() || track.width == 0 || track.height == 0 {
+            return false;
+        }
+
+        let inside = mouse.column >= track.x
+            && mouse.column < track.x + track.width
+            && mouse.row >= track.y
+            && mouse.row < track.y + track.height;
+
+        let previous = self.value;
+        match mouse.kind {
+            SliderMouseKind::Down | SliderMouseKind::Drag if self.dragging => {
+                self.set_from_pointer(mouse, track, orientation)
+            }
+            SliderMouseKind::Down if inside => {
+                self.dragging = true;
+                self.set_from_pointer(mouse, track, orientation);
+            }
+            SliderMouseKind::Up | SliderMouseKind::Moved => {
+                self.dragging = false;
+                return false;
+            }
+            SliderMouseKind::ScrollUp if inside => self.step_up(),
+            SliderMouseKind::ScrollDown if inside => self.step_down(),
+            _ => return false,
+        }
+        self.value != previous
+    }
+
+    /// Gets where the slider was last rendered
+    ///
+    /// Recorded when the slider is rendered as a
+    /// [`StatefulWidget`](ratatui::widgets::StatefulWidget); `None` before the
+    /// first render or if the slider did not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+    /// use tui_slider::{Slider, SliderState};
+    ///
+    /// let mut state = SliderState::new(50.0, 0.0, 100.0);
+    /// assert!(state.layout().is_none());
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// Slider::default().render(area, &mut buf, &mut state);
+    ///
+    /// let layout = state.layout().unwrap();
+    /// assert!(layout.is_over_track(3, 0));
+    /// ```
+    pub fn layout(&self) -> Option<&SliderLayout> {
+        self.layout.as_ref()
+    }
+
+    /// Records where the slider was rendered
+    pub(crate) fn set_layout(&mut self, layout: Option<SliderLayout>) {
+        self.layout = layout;
+    }
+
+    /// Sets the value from a pointer position over the track, snapped to the step
+    fn set_from_pointer(
+        &mut self,
+        mouse: SliderMouse,
+        track: Rect,
+        orientation: SliderOrientation,
+    ) {
+        let (offset, length) = match orientation {
+            SliderOrientation::Horizontal => (
+                mouse.column.clamp(track.x, track.x + track.width - 1) - track.x,
+                track.width,
+            ),
+            SliderOrientation::Vertical => (
+                track.y + track.height - 1 - mouse.row.clamp(track.y, track.y + track.height - 1),
+                track.height,
+            ),
+        };
+
+        let value = if length > 1 && offset == length - 1 {
+            // Exactly the maximum, without rounding errors
+            self.max
+        } else if length > 1 {
+            self.min + self.range() * (offset as f64 / (length - 1) as f64)
+        } else {
+            self.min
+        };
+        self.set_value(self.snap_to_step(value));
+    }
+
+    /// Number of decimals needed to show a multiple of the step size
+    fn step_decimals(&self) -> usize {
+        let step = self.step.to_string();
+        step.split_once('.')
+            .map(|(_, fraction)| fraction.len().min(6))
+            .unwrap_or(0)
+    }
+
+    /// Rounds a value to the nearest step counted from the minimum
+    ///
+    /// The bounds are always reachable: a value at or past either one is kept
+    /// at it, and the maximum wins when it is nearer than the last step.
+    fn snap_to_step(&self, value: f64) -> f64 {
+        if value <= self.min {
+            return self.min;
+        }
+        if value >= self.max {
+            return self.max;
+        }
+        let steps = ((value - self.min) / self.step).round();
+        let snapped = self.min + steps * self.step;
+        let scale = 10f64.powi(self.step_decimals() as i32);
+        let snapped = ((snapped * scale).round() / scale).clamp(self.min, self.max);
+        if self.max - value < (value - snapped).abs() {
+            self.max
+        } else {
+            snapped
+        }
     }
 }
 
@@ -669,6 +1339,203 @@ impl Default for SliderState {
     }
 }
 
+/// Where a slider was drawn in the last stateful render
+///
+/// Stored in [`SliderState`] so mouse events can be mapped back to values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliderLayout {
+    /// Area covered by the bar
+    pub track: Rect,
+    /// Top-left cell of the handle, if it was drawn
+    ///
+    /// On a thick track the handle spans the whole thickness from here.
+    pub handle: Option<Position>,
+    /// Orientation the slider was drawn with
+    pub orientation: SliderOrientation,
+}
+
+impl SliderLayout {
+    /// Creates a layout for a track without a handle
+    pub fn new(track: Rect, orientation: SliderOrientation) -> Self {
+        Self {
+            track,
+            handle: None,
+            orientation,
+        }
+    }
+
+    /// Returns true if the cell lies on the track
+    pub fn is_over_track(&self, column: u16, row: u16) -> bool {
+        self.track.contains(Position::new(column, row))
+    }
+
+    /// Returns true if the cell is the handle's cell
+    ///
+    /// On a thick track, any cell of the handle's column (horizontal) or row
+    /// (vertical) within the track counts.
+    pub fn is_over_handle(&self, column: u16, row: u16) -> bool {
+        let Some(handle) = self.handle else {
+            return false;
+        };
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                column == handle.x && row >= handle.y && row < self.track.bottom()
+            }
+            SliderOrientation::Vertical => {
+                row == handle.y && column >= handle.x && column < self.track.right()
+            }
+        }
+    }
+}
+
+/// Text field for typing an exact slider value
+///
+/// Created by [`SliderState::start_editing`]. Only characters that can appear in
+/// a number are accepted; the cursor is a character index into the text.
+///
+/// # Examples
+///
+/// ```
+/// use tui_slider::SliderState;
+///
+/// let mut state = SliderState::new(12.0, 0.0, 100.0);
+/// state.start_editing();
+///
+/// let editor = state.editor_mut().unwrap();
+/// editor.move_home();
+/// editor.insert_char('-');
+/// assert_eq!(editor.text(), "-12");
+/// assert_eq!(editor.cursor(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueEditor {
+    /// Typed text
+    text: String,
+    /// Cursor position as a character index
+    cursor: usize,
+}
+
+impl ValueEditor {
+    /// Creates an editor holding the given text with the cursor at the end
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        let text = text.into();
+        let cursor = text.chars().count();
+        Self { text, cursor }
+    }
+
+    /// Gets the typed text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the cursor position as a character index
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Inserts a character at the cursor
+    ///
+    /// Returns false (and leaves the text untouched) for characters that cannot
+    /// be part of a number.
+    pub fn insert_char(&mut self, c: char) -> bool {
+        if !(c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+')) {
+            return false;
+        }
+        let index = self.byte_index(self.cursor);
+        self.text.insert(index, c);
+        self.cursor += 1;
+        true
+    }
+
+    /// Removes the character before the cursor (backspace)
+    pub fn delete_backward(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        let index = self.byte_index(self.cursor);
+        self.text.remove(index);
+        true
+    }
+
+    /// Removes the character under the cursor (delete)
+    pub fn delete_forward(&mut self) -> bool {
+        if self.cursor >= self.text.chars().count() {
+            return false;
+        }
+        let index = self.byte_index(self.cursor);
+        self.text.remove(index);
+        true
+    }
+
+    /// Removes all text
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor one character to the left
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one character to the right
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.text.chars().count());
+    }
+
+    /// Moves the cursor to the start of the text
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the text
+    pub fn move_end(&mut self) {
+        self.cursor = self.text.chars().count();
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(cursor)
+            .map(|(index, _)| index)
+            .unwrap_or(self.text.len())
+    }
+}
+
+/// Reasons a typed value is rejected by [`SliderState::commit_edit`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditError {
+    /// The state is not in edit mode
+    NotEditing,
+    /// The edit field is empty
+    Empty,
+    /// The text is not a valid number
+    Invalid,
+    /// The number lies outside the slider bounds
+    OutOfRange {
+        /// Minimum accepted value
+        min: f64,
+        /// Maximum accepted value
+        max: f64,
+    },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::NotEditing => write!(f, "slider is not being edited"),
+            EditError::Empty => write!(f, "no value entered"),
+            EditError::Invalid => write!(f, "not a valid number"),
+            EditError::OutOfRange { min, max } => {
+                write!(f, "value must be between {} and {}", min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -864,4 +1731,339 @@ mod tests {
         state.step_up();
         assert_eq!(state.value(), 75.0);
     }
+
+    #[test]
+    fn test_default_thresholds() {
+        assert!(SliderState::new(32.0, 0.0, 100.0).is_low());
+        assert!(SliderState::new(33.0, 0.0, 100.0).is_medium());
+        assert!(SliderState::new(67.0, 0.0, 100.0).is_high());
+    }
+
+    #[test]
+    fn test_set_thresholds() {
+        let mut state = SliderState::new(70.0, 0.0, 100.0);
+        state.set_thresholds(0.5, 0.8);
+        assert!(state.is_medium());
+        assert!(!state.is_high());
+
+        state.set_value(40.0);
+        assert!(state.is_low());
+
+        state.set_value(80.0);
+        assert!(state.is_high());
+        assert_eq!(state.low_threshold_value(), 50.0);
+        assert_eq!(state.high_threshold_value(), 80.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "thresholds must satisfy")]
+    fn test_set_thresholds_invalid() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        state.set_thresholds(0.8, 0.5);
+    }
+
+    #[test]
+    fn test_start_editing_prefills_value() {
+        let mut state = SliderState::with_step(0.25, 0.0, 1.0, 0.05);
+        state.start_editing();
+        assert_eq!(state.editor().unwrap().text(), "0.25");
+        assert_eq!(state.editor().unwrap().cursor(), 4);
+    }
+
+    #[test]
+    fn test_editor_cursor_operations() {
+        let mut editor = ValueEditor::new("123");
+        editor.move_left();
+        assert!(editor.delete_backward());
+        assert_eq!(editor.text(), "13");
+        assert_eq!(editor.cursor(), 1);
+
+        assert!(editor.delete_forward());
+        assert_eq!(editor.text(), "1");
+        assert!(!editor.delete_forward());
+
+        editor.move_home();
+        assert!(!editor.delete_backward());
+        assert!(!editor.insert_char('x'));
+        assert!(editor.insert_char('4'));
+        assert_eq!(editor.text(), "41");
+
+        editor.move_end();
+        editor.move_right();
+        assert_eq!(editor.cursor(), 2);
+    }
+
+    #[test]
+    fn test_commit_edit() {
+        let mut state = SliderState::new(50.0, 0.0, 1000.0);
+        state.set_number_format(NumberFormat::new().decimal_separator(','));
+        state.start_editing();
+        let editor = state.editor_mut().unwrap();
+        editor.clear();
+        for c in "440,4".chars() {
+            editor.insert_char(c);
+        }
+
+        assert_eq!(state.commit_edit(), Ok(440.0));
+        assert_eq!(state.value(), 440.0);
+        assert!(!state.is_editing());
+    }
+
+    fn commit_text(state: &mut SliderState, text: &str) -> Result<f64, EditError> {
+        state.start_editing();
+        let editor = state.editor_mut().unwrap();
+        editor.clear();
+        for c in text.chars() {
+            editor.insert_char(c);
+        }
+        let result = state.commit_edit();
+        state.cancel_edit();
+        result
+    }
+
+    #[test]
+    fn test_commit_edit_uses_number_format() {
+        let mut state = SliderState::with_step(0.0, 0.0, 10_000.0, 0.5);
+
+        // Default format: `.` decimals, no grouping
+        assert_eq!(commit_text(&mut state, "1.5"), Ok(1.5));
+        assert_eq!(commit_text(&mut state, "1,000"), Err(EditError::Invalid));
+
+        // English: `,` groups, `.` decimals
+        state.set_number_format(NumberFormat::new().grouping_separator(','));
+        assert_eq!(commit_text(&mut state, "1,000"), Ok(1000.0));
+        assert_eq!(commit_text(&mut state, "1,234.5"), Ok(1234.5));
+        assert_eq!(commit_text(&mut state, "1,5"), Err(EditError::Invalid));
+
+        // European: `.` groups, `,` decimals
+        state.set_number_format(
+            NumberFormat::new()
+                .decimal_separator(',')
+                .grouping_separator('.'),
+        );
+        assert_eq!(commit_text(&mut state, "1.000"), Ok(1000.0));
+        assert_eq!(commit_text(&mut state, "1.234,5"), Ok(1234.5));
+        assert_eq!(commit_text(&mut state, "1.5"), Err(EditError::Invalid));
+        assert_eq!(commit_text(&mut state, "1,2,3"), Err(EditError::Invalid));
+    }
+
+    #[test]
+    fn test_commit_edit_errors_keep_editor_open() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        assert_eq!(state.commit_edit(), Err(EditError::NotEditing));
+
+        state.start_editing();
+        state.editor_mut().unwrap().insert_char('-');
+        assert_eq!(state.commit_edit(), Err(EditError::Invalid));
+        assert!(state.is_editing());
+
+        state.editor_mut().unwrap().clear();
+        state.editor_mut().unwrap().insert_char('-');
+        state.editor_mut().unwrap().insert_char('1');
+        assert_eq!(
+            state.commit_edit(),
+            Err(EditError::OutOfRange {
+                min: 0.0,
+                max: 100.0
+            })
+        );
+        assert_eq!(state.value(), 50.0);
+    }
+
+    #[test]
+    fn test_commit_edit_snaps_to_step() {
+        let mut state = SliderState::with_step(0.0, 0.0, 1.0, 0.1);
+        state.start_editing();
+        let editor = state.editor_mut().unwrap();
+        editor.clear();
+        for c in "0.44".chars() {
+            editor.insert_char(c);
+        }
+        assert_eq!(state.commit_edit(), Ok(0.4));
+    }
+
+    #[test]
+    fn test_max_off_step_is_reachable() {
+        let mut state = SliderState::with_step(0.0, 0.0, 100.0, 30.0);
+        state.start_editing();
+        let editor = state.editor_mut().unwrap();
+        editor.clear();
+        for c in "100".chars() {
+            editor.insert_char(c);
+        }
+        assert_eq!(state.commit_edit(), Ok(100.0));
+
+        // Nearer the maximum than the last step
+        assert_eq!(state.snap_to_step(97.0), 100.0);
+        assert_eq!(state.snap_to_step(94.0), 90.0);
+
+        state.set_value(0.0);
+        let track = Rect::new(0, 0, 11, 1);
+        let click = SliderMouse::new(SliderMouseKind::Down, 10, 0);
+        assert!(state.handle_mouse(click, track, SliderOrientation::Horizontal));
+        assert_eq!(state.value(), 100.0);
+    }
+
+    #[test]
+    fn test_cancel_edit() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        state.start_editing();
+        state.editor_mut().unwrap().clear();
+        state.cancel_edit();
+        assert!(!state.is_editing());
+        assert_eq!(state.value(), 50.0);
+    }
+
+    #[test]
+    fn test_handle_keys() {
+        let mut state = SliderState::with_step(50.0, 0.0, 100.0, 2.0);
+
+        assert!(state.handle_input(SliderKey::Up));
+        assert_eq!(state.value(), 52.0);
+        assert!(state.handle_input(SliderKey::Left));
+        assert_eq!(state.value(), 50.0);
+        assert!(state.handle_input(SliderKey::PageDown));
+        assert_eq!(state.value(), 30.0);
+        assert!(state.handle_input(SliderKey::Home));
+        assert!(!state.handle_input(SliderKey::Home));
+        assert!(!state.handle_input(SliderKey::Char('x')));
+        assert!(!state.handle_input(SliderInput::Unsupported));
+    }
+
+    #[test]
+    fn test_handle_keys_while_editing() {
+        let mut state = SliderState::new(12.0, 0.0, 100.0);
+        assert!(state.handle_input(SliderKey::Enter));
+        assert!(state.is_editing());
+
+        state.handle_input(SliderKey::Home);
+        state.handle_input(SliderKey::Delete);
+        state.handle_input(SliderKey::Char('4'));
+        assert_eq!(state.editor().unwrap().text(), "42");
+
+        // Arrow keys edit the text rather than the value
+        assert!(!state.handle_input(SliderKey::Up));
+        assert_eq!(state.value(), 12.0);
+
+        assert!(state.handle_input(SliderKey::Enter));
+        assert_eq!(state.value(), 42.0);
+
+        state.handle_input(SliderKey::Enter);
+        assert!(state.handle_input(SliderKey::Esc));
+        assert!(!state.is_editing());
+    }
+
+    #[test]
+    fn test_handle_mouse_horizontal() {
+        let mut state = SliderState::new(0.0, 0.0, 100.0);
+        let track = Rect::new(0, 0, 11, 1);
+        let orientation = SliderOrientation::Horizontal;
+
+        // Presses outside the track are ignored
+        let outside = SliderMouse::new(SliderMouseKind::Down, 5, 3);
+        assert!(!state.handle_mouse(outside, track, orientation));
+
+        let press = SliderMouse::new(SliderMouseKind::Down, 10, 0);
+        assert!(state.handle_mouse(press, track, orientation));
+        assert_eq!(state.value(), 100.0);
+
+        // Dragging past the end clamps to the track
+        let drag = SliderMouse::new(SliderMouseKind::Drag, 40, 7);
+        assert!(!state.handle_mouse(drag, track, orientation));
+        assert_eq!(state.value(), 100.0);
+
+        let scroll = SliderMouse::new(SliderMouseKind::ScrollDown, 2, 0);
+        assert!(state.handle_mouse(scroll, track, orientation));
+        assert_eq!(state.value(), 99.0);
+    }
+
+    #[test]
+    fn test_handle_mouse_vertical() {
+        let mut state = SliderState::with_step(0.0, 0.0, 10.0, 1.0);
+        let track = Rect::new(2, 1, 1, 6);
+
+        let press = SliderMouse::new(SliderMouseKind::Down, 2, 1);
+        state.handle_mouse(press, track, SliderOrientation::Vertical);
+        assert_eq!(state.value(), 10.0);
+
+        let drag = SliderMouse::new(SliderMouseKind::Drag, 2, 4);
+        state.handle_mouse(drag, track, SliderOrientation::Vertical);
+        assert_eq!(state.value(), 4.0);
+    }
+
+    #[test]
+    fn test_drag_moves_only_pressed_slider() {
+        let mut first = SliderState::new(0.0, 0.0, 100.0);
+        let mut second = SliderState::new(0.0, 0.0, 100.0);
+        first.set_layout(Some(SliderLayout::new(
+            Rect::new(0, 0, 11, 1),
+            SliderOrientation::Horizontal,
+        )));
+        second.set_layout(Some(SliderLayout::new(
+            Rect::new(0, 2, 11, 1),
+            SliderOrientation::Horizontal,
+        )));
+
+        let events = [
+            SliderMouse::new(SliderMouseKind::Down, 2, 0),
+            SliderMouse::new(SliderMouseKind::Drag, 8, 1),
+            SliderMouse::new(SliderMouseKind::Up, 8, 1),
+        ];
+        for event in events {
+            first.handle_input(event);
+            second.handle_input(event);
+        }
+        assert_eq!(first.value(), 80.0);
+        assert_eq!(second.value(), 0.0);
+
+        // The drag ended with the release
+        let drag = SliderMouse::new(SliderMouseKind::Drag, 4, 0);
+        assert!(!first.handle_input(drag));
+        assert_eq!(first.value(), 80.0);
+    }
+
+    #[test]
+    fn test_held_press_drags_outside_track() {
+        let mut state = SliderState::new(0.0, 0.0, 100.0);
+        let track = Rect::new(0, 0, 11, 1);
+        let orientation = SliderOrientation::Horizontal;
+
+        // Backends without drag events report a held button as presses
+        let press = SliderMouse::new(SliderMouseKind::Down, 2, 0);
+        state.handle_mouse(press, track, orientation);
+        let held = SliderMouse::new(SliderMouseKind::Down, 7, 3);
+        assert!(state.handle_mouse(held, track, orientation));
+        assert_eq!(state.value(), 70.0);
+
+        let released = SliderMouse::new(SliderMouseKind::Moved, 9, 3);
+        state.handle_mouse(released, track, orientation);
+        assert!(!state.handle_mouse(held, track, orientation));
+    }
+
+    #[test]
+    fn test_handle_input_mouse_uses_layout() {
+        let mut state = SliderState::new(0.0, 0.0, 100.0);
+        let press = SliderMouse::new(SliderMouseKind::Down, 5, 2);
+
+        // Ignored until the slider has been rendered
+        assert!(!state.handle_input(press));
+
+        state.set_layout(Some(SliderLayout::new(
+            Rect::new(0, 2, 11, 1),
+            SliderOrientation::Horizontal,
+        )));
+        assert!(state.handle_input(press));
+        assert_eq!(state.value(), 50.0);
+    }
+
+    #[test]
+    fn test_layout_hit_testing() {
+        let mut layout = SliderLayout::new(Rect::new(4, 1, 1, 5), SliderOrientation::Vertical);
+        layout.handle = Some(Position::new(4, 3));
+
+        assert!(layout.is_over_track(4, 5));
+        assert!(!layout.is_over_track(5, 5));
+        assert!(layout.is_over_handle(4, 3));
+        assert!(!layout.is_over_handle(4, 4));
+    }
 }
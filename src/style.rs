@@ -30,9 +30,129 @@
 //!     .empty_color(Color::DarkGray)
 //!     .handle_color(Color::White);
 //! ```
+//!
+//! ## Listing presets
+//!
+//! ```rust
+//! use tui_slider::style::{SliderStyle, StyleCategory};
+//!
+//! // Every preset, e.g. for a style picker
+//! for style in SliderStyle::all() {
+//!     println!("{}", style.name);
+//! }
+//!
+//! // Restore a choice saved by name
+//! let style = SliderStyle::by_name("Segmented Blocks").unwrap_or_default();
+//! assert_eq!(style.category(), Some(StyleCategory::Segmented));
+//! ```
 
-use crate::symbols;
+use crate::symbols::{self, SymbolSet};
 use ratatui::style::Color;
+use std::borrow::Cow;
+
+/// Group of related style presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleCategory {
+    /// General purpose sliders
+    General,
+    /// Progress bars, usually drawn without a handle
+    Progress,
+    /// Vertical sliders such as mixers and equalizers
+    Vertical,
+    /// Horizontal sliders such as volume controls and seekbars
+    Horizontal,
+    /// Bars drawn as discrete segments
+    Segmented,
+}
+
+impl StyleCategory {
+    /// Get the display name of the category
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::StyleCategory;
+    ///
+    /// assert_eq!(StyleCategory::Progress.name(), "Progress");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            StyleCategory::General => "General",
+            StyleCategory::Progress => "Progress",
+            StyleCategory::Vertical => "Vertical",
+            StyleCategory::Horizontal => "Horizontal",
+            StyleCategory::Segmented => "Segmented",
+        }
+    }
+
+    /// Get all categories as a list
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::StyleCategory;
+    ///
+    /// assert_eq!(StyleCategory::all().len(), 5);
+    /// ```
+    pub fn all() -> &'static [StyleCategory] {
+        &[
+            StyleCategory::General,
+            StyleCategory::Progress,
+            StyleCategory::Vertical,
+            StyleCategory::Horizontal,
+            StyleCategory::Segmented,
+        ]
+    }
+}
+
+/// Constructor of a preset style
+type Preset = fn() -> SliderStyle;
+
+/// Every preset with its category, in display order
+const PRESETS: &[(StyleCategory, Preset)] = &[
+    (StyleCategory::General, SliderStyle::default_style),
+    (StyleCategory::General, SliderStyle::blocks),
+    (StyleCategory::General, SliderStyle::dots),
+    (StyleCategory::General, SliderStyle::arrows),
+    (StyleCategory::General, SliderStyle::minimal),
+    (StyleCategory::General, SliderStyle::double_line),
+    (StyleCategory::General, SliderStyle::wave),
+    (StyleCategory::General, SliderStyle::progress),
+    (StyleCategory::General, SliderStyle::thick),
+    (StyleCategory::General, SliderStyle::gradient),
+    (StyleCategory::General, SliderStyle::rounded),
+    (StyleCategory::General, SliderStyle::retro),
+    (StyleCategory::Segmented, SliderStyle::segmented),
+    (StyleCategory::Segmented, SliderStyle::segmented_blocks),
+    (StyleCategory::Segmented, SliderStyle::segmented_dots),
+    (StyleCategory::Segmented, SliderStyle::segmented_bars),
+    (StyleCategory::Segmented, SliderStyle::segmented_squares),
+    (StyleCategory::Segmented, SliderStyle::segmented_diamonds),
+    (StyleCategory::Segmented, SliderStyle::segmented_stars),
+    (StyleCategory::Segmented, SliderStyle::segmented_arrows),
+    (StyleCategory::Segmented, SliderStyle::segmented_thick),
+    (StyleCategory::Progress, SliderStyle::progress_download),
+    (StyleCategory::Progress, SliderStyle::progress_upload),
+    (StyleCategory::Progress, SliderStyle::progress_health),
+    (StyleCategory::Progress, SliderStyle::progress_mana),
+    (StyleCategory::Progress, SliderStyle::progress_experience),
+    (StyleCategory::Progress, SliderStyle::progress_loading),
+    (StyleCategory::Progress, SliderStyle::progress_installation),
+    (StyleCategory::Progress, SliderStyle::progress_battery),
+    (StyleCategory::Vertical, SliderStyle::vertical),
+    (StyleCategory::Vertical, SliderStyle::vertical_blocks),
+    (StyleCategory::Vertical, SliderStyle::vertical_gradient),
+    (StyleCategory::Vertical, SliderStyle::vertical_dots),
+    (StyleCategory::Vertical, SliderStyle::vertical_squares),
+    (StyleCategory::Vertical, SliderStyle::vertical_equalizer),
+    (StyleCategory::Horizontal, SliderStyle::horizontal),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_thick),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_blocks),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_gradient),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_dots),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_squares),
+    (StyleCategory::Horizontal, SliderStyle::horizontal_double),
+];
 
 /// Style configuration for sliders
 ///
@@ -41,13 +161,13 @@ use ratatui::style::Color;
 #[derive(Debug, Clone)]
 pub struct SliderStyle {
     /// Display name for the style
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// Symbol for the filled portion
-    pub filled_symbol: &'static str,
+    pub filled_symbol: Cow<'static, str>,
     /// Symbol for the empty portion
-    pub empty_symbol: &'static str,
+    pub empty_symbol: Cow<'static, str>,
     /// Symbol for the slider handle/thumb
-    pub handle_symbol: &'static str,
+    pub handle_symbol: Cow<'static, str>,
     /// Color for filled portion
     pub filled_color: Color,
     /// Color for empty portion
@@ -62,10 +182,10 @@ impl SliderStyle {
     /// Default style - clean and professional
     pub fn default_style() -> Self {
         Self {
-            name: "Default",
-            filled_symbol: symbols::FILLED_THICK_LINE,
-            empty_symbol: symbols::EMPTY_THIN_LINE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Default"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THICK_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -76,10 +196,10 @@ impl SliderStyle {
     /// Block style - bold and solid
     pub fn blocks() -> Self {
         Self {
-            name: "Blocks",
-            filled_symbol: symbols::FILLED_BLOCK,
-            empty_symbol: symbols::FILLED_LIGHT_SHADE,
-            handle_symbol: symbols::FILLED_DARK_SHADE,
+            name: Cow::Borrowed("Blocks"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BLOCK),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::FILLED_DARK_SHADE),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -90,10 +210,10 @@ impl SliderStyle {
     /// Dotted style - braille patterns
     pub fn dots() -> Self {
         Self {
-            name: "Dots",
-            filled_symbol: symbols::FILLED_BRAILLE,
-            empty_symbol: symbols::EMPTY_BRAILLE,
-            handle_symbol: symbols::HANDLE_BLACK_CIRCLE,
+            name: Cow::Borrowed("Dots"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BRAILLE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_BRAILLE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_BLACK_CIRCLE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -104,10 +224,10 @@ impl SliderStyle {
     /// Arrow style - geometric shapes
     pub fn arrows() -> Self {
         Self {
-            name: "Arrows",
-            filled_symbol: symbols::FILLED_BAR,
-            empty_symbol: symbols::EMPTY_BAR_OUTLINE,
-            handle_symbol: symbols::HANDLE_DIAMOND,
+            name: Cow::Borrowed("Arrows"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BAR),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_BAR_OUTLINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DIAMOND),
             filled_color: Color::Magenta,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -118,10 +238,10 @@ impl SliderStyle {
     /// Minimal style - clean and subtle
     pub fn minimal() -> Self {
         Self {
-            name: "Minimal",
-            filled_symbol: symbols::FILLED_THIN_LINE,
-            empty_symbol: symbols::EMPTY_SPACE,
-            handle_symbol: symbols::HANDLE_VERTICAL_BAR,
+            name: Cow::Borrowed("Minimal"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THIN_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_SPACE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_VERTICAL_BAR),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::Cyan,
@@ -132,10 +252,10 @@ impl SliderStyle {
     /// Double line style - formal appearance
     pub fn double_line() -> Self {
         Self {
-            name: "Double Line",
-            filled_symbol: symbols::FILLED_DOUBLE_LINE,
-            empty_symbol: symbols::EMPTY_THIN_LINE,
-            handle_symbol: symbols::HANDLE_DOUBLE_CIRCLE,
+            name: Cow::Borrowed("Double Line"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_DOUBLE_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DOUBLE_CIRCLE),
             filled_color: Color::Red,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -146,10 +266,10 @@ impl SliderStyle {
     /// Wave style - fluid appearance
     pub fn wave() -> Self {
         Self {
-            name: "Wave",
-            filled_symbol: symbols::FILLED_WAVE,
-            empty_symbol: symbols::EMPTY_WAVE,
-            handle_symbol: symbols::HANDLE_DOUBLE_DIAMOND,
+            name: Cow::Borrowed("Wave"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_WAVE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_WAVE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DOUBLE_DIAMOND),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -160,10 +280,10 @@ impl SliderStyle {
     /// Progress style - progress bar look
     pub fn progress() -> Self {
         Self {
-            name: "Progress",
-            filled_symbol: symbols::FILLED_PROGRESS,
-            empty_symbol: symbols::EMPTY_PROGRESS,
-            handle_symbol: symbols::HANDLE_TRIANGLE_RIGHT,
+            name: Cow::Borrowed("Progress"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_PROGRESS),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_PROGRESS),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_TRIANGLE_RIGHT),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::Yellow,
@@ -174,10 +294,10 @@ impl SliderStyle {
     /// Thick style - bold appearance
     pub fn thick() -> Self {
         Self {
-            name: "Thick",
-            filled_symbol: symbols::FILLED_BAR,
-            empty_symbol: symbols::FILLED_BAR,
-            handle_symbol: symbols::HANDLE_SQUARE,
+            name: Cow::Borrowed("Thick"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BAR),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_BAR),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_SQUARE),
             filled_color: Color::Magenta,
             empty_color: Color::Rgb(60, 60, 60),
             handle_color: Color::White,
@@ -188,10 +308,10 @@ impl SliderStyle {
     /// Gradient style - shaded effect
     pub fn gradient() -> Self {
         Self {
-            name: "Gradient",
-            filled_symbol: symbols::FILLED_DARK_SHADE,
-            empty_symbol: symbols::FILLED_LIGHT_SHADE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Gradient"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_DARK_SHADE),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::Cyan,
@@ -202,10 +322,10 @@ impl SliderStyle {
     /// Rounded style - soft appearance
     pub fn rounded() -> Self {
         Self {
-            name: "Rounded",
-            filled_symbol: symbols::FILLED_THIN_LINE,
-            empty_symbol: symbols::EMPTY_DASHED,
-            handle_symbol: symbols::HANDLE_LARGE_CIRCLE,
+            name: Cow::Borrowed("Rounded"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THIN_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_DASHED),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_LARGE_CIRCLE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -216,10 +336,10 @@ impl SliderStyle {
     /// Retro style - old-school ASCII
     pub fn retro() -> Self {
         Self {
-            name: "Retro",
-            filled_symbol: symbols::FILLED_HASH,
-            empty_symbol: symbols::EMPTY_DOT,
-            handle_symbol: symbols::HANDLE_AT,
+            name: Cow::Borrowed("Retro"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_HASH),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_DOT),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_AT),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -230,10 +350,10 @@ impl SliderStyle {
     /// Segmented style - discrete segments with spaces
     pub fn segmented() -> Self {
         Self {
-            name: "Segmented",
-            filled_symbol: "─",
-            empty_symbol: "─",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Segmented"),
+            filled_symbol: Cow::Borrowed("─"),
+            empty_symbol: Cow::Borrowed("─"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Red,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -244,10 +364,10 @@ impl SliderStyle {
     /// Segmented blocks style
     pub fn segmented_blocks() -> Self {
         Self {
-            name: "Segmented Blocks",
-            filled_symbol: "█",
-            empty_symbol: "░",
-            handle_symbol: symbols::HANDLE_SQUARE,
+            name: Cow::Borrowed("Segmented Blocks"),
+            filled_symbol: Cow::Borrowed("█"),
+            empty_symbol: Cow::Borrowed("░"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_SQUARE),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -258,10 +378,10 @@ impl SliderStyle {
     /// Segmented dots style
     pub fn segmented_dots() -> Self {
         Self {
-            name: "Segmented Dots",
-            filled_symbol: "●",
-            empty_symbol: "○",
-            handle_symbol: symbols::HANDLE_DIAMOND,
+            name: Cow::Borrowed("Segmented Dots"),
+            filled_symbol: Cow::Borrowed("●"),
+            empty_symbol: Cow::Borrowed("○"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DIAMOND),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::Yellow,
@@ -272,10 +392,10 @@ impl SliderStyle {
     /// Segmented bars style
     pub fn segmented_bars() -> Self {
         Self {
-            name: "Segmented Bars",
-            filled_symbol: "│",
-            empty_symbol: "┆",
-            handle_symbol: symbols::HANDLE_TRIANGLE_RIGHT,
+            name: Cow::Borrowed("Segmented Bars"),
+            filled_symbol: Cow::Borrowed("│"),
+            empty_symbol: Cow::Borrowed("┆"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_TRIANGLE_RIGHT),
             filled_color: Color::Magenta,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -286,10 +406,10 @@ impl SliderStyle {
     /// Segmented squares style
     pub fn segmented_squares() -> Self {
         Self {
-            name: "Segmented Squares",
-            filled_symbol: "■",
-            empty_symbol: "□",
-            handle_symbol: symbols::HANDLE_DOUBLE_CIRCLE,
+            name: Cow::Borrowed("Segmented Squares"),
+            filled_symbol: Cow::Borrowed("■"),
+            empty_symbol: Cow::Borrowed("□"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DOUBLE_CIRCLE),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::Cyan,
@@ -300,10 +420,10 @@ impl SliderStyle {
     /// Segmented diamonds style
     pub fn segmented_diamonds() -> Self {
         Self {
-            name: "Segmented Diamonds",
-            filled_symbol: "◆",
-            empty_symbol: "◇",
-            handle_symbol: symbols::HANDLE_HEXAGON,
+            name: Cow::Borrowed("Segmented Diamonds"),
+            filled_symbol: Cow::Borrowed("◆"),
+            empty_symbol: Cow::Borrowed("◇"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HEXAGON),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -314,10 +434,10 @@ impl SliderStyle {
     /// Segmented stars style
     pub fn segmented_stars() -> Self {
         Self {
-            name: "Segmented Stars",
-            filled_symbol: "★",
-            empty_symbol: "☆",
-            handle_symbol: symbols::HANDLE_SPARKLE,
+            name: Cow::Borrowed("Segmented Stars"),
+            filled_symbol: Cow::Borrowed("★"),
+            empty_symbol: Cow::Borrowed("☆"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_SPARKLE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::Cyan,
@@ -328,10 +448,10 @@ impl SliderStyle {
     /// Segmented arrows style
     pub fn segmented_arrows() -> Self {
         Self {
-            name: "Segmented Arrows",
-            filled_symbol: "▶",
-            empty_symbol: "▷",
-            handle_symbol: symbols::HANDLE_TRIANGLE_RIGHT,
+            name: Cow::Borrowed("Segmented Arrows"),
+            filled_symbol: Cow::Borrowed("▶"),
+            empty_symbol: Cow::Borrowed("▷"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_TRIANGLE_RIGHT),
             filled_color: Color::Red,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -342,10 +462,10 @@ impl SliderStyle {
     /// Segmented thick style
     pub fn segmented_thick() -> Self {
         Self {
-            name: "Segmented Thick",
-            filled_symbol: "━",
-            empty_symbol: "╌",
-            handle_symbol: symbols::HANDLE_LARGE_CIRCLE,
+            name: Cow::Borrowed("Segmented Thick"),
+            filled_symbol: Cow::Borrowed("━"),
+            empty_symbol: Cow::Borrowed("╌"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_LARGE_CIRCLE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -370,12 +490,23 @@ impl SliderStyle {
     ///     .empty_color(Color::Rgb(50, 50, 50))
     ///     .handle_color(Color::White);
     /// ```
-    pub fn custom(name: &'static str) -> Self {
+    ///
+    /// Names and symbols may also be owned strings, such as ones read from a
+    /// config file:
+    ///
+    /// ```rust
+    /// use tui_slider::style::SliderStyle;
+    ///
+    /// let (name, filled) = (String::from("From Disk"), String::from("▰"));
+    /// let custom = SliderStyle::custom(name).filled_symbol(filled);
+    /// assert_eq!(custom.name, "From Disk");
+    /// ```
+    pub fn custom(name: impl Into<Cow<'static, str>>) -> Self {
         Self {
-            name,
-            filled_symbol: symbols::FILLED_THICK_LINE,
-            empty_symbol: symbols::EMPTY_THIN_LINE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: name.into(),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THICK_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -384,20 +515,20 @@ impl SliderStyle {
     }
 
     /// Set the filled symbol for the custom style
-    pub fn filled_symbol(mut self, symbol: &'static str) -> Self {
-        self.filled_symbol = symbol;
+    pub fn filled_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.filled_symbol = symbol.into();
         self
     }
 
     /// Set the empty symbol for the custom style
-    pub fn empty_symbol(mut self, symbol: &'static str) -> Self {
-        self.empty_symbol = symbol;
+    pub fn empty_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.empty_symbol = symbol.into();
         self
     }
 
     /// Set the handle symbol for the custom style
-    pub fn handle_symbol(mut self, symbol: &'static str) -> Self {
-        self.handle_symbol = symbol;
+    pub fn handle_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.handle_symbol = symbol.into();
         self
     }
 
@@ -424,6 +555,33 @@ impl SliderStyle {
         self.segmented = enabled;
         self
     }
+
+    /// Set the filled, empty and handle symbols from a symbol set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::SliderStyle;
+    /// use tui_slider::symbols;
+    ///
+    /// let custom = SliderStyle::custom("Stars").symbol_set(symbols::STYLE_STAR);
+    /// assert_eq!(custom.filled_symbol, symbols::STYLE_STAR.filled);
+    /// ```
+    pub fn symbol_set(mut self, set: SymbolSet) -> Self {
+        self.filled_symbol = set.filled;
+        self.empty_symbol = set.empty;
+        self.handle_symbol = set.handle;
+        self
+    }
+
+    /// Get the filled, empty and handle symbols as a symbol set
+    pub fn symbols(&self) -> SymbolSet {
+        SymbolSet {
+            filled: self.filled_symbol.clone(),
+            empty: self.empty_symbol.clone(),
+            handle: self.handle_symbol.clone(),
+        }
+    }
 }
 
 /// Progress bar style presets
@@ -433,10 +591,10 @@ impl SliderStyle {
     /// Download progress bar style
     pub fn progress_download() -> Self {
         Self {
-            name: "Download",
-            filled_symbol: "█",
-            empty_symbol: "░",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Download"),
+            filled_symbol: Cow::Borrowed("█"),
+            empty_symbol: Cow::Borrowed("░"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -447,10 +605,10 @@ impl SliderStyle {
     /// Upload progress bar style
     pub fn progress_upload() -> Self {
         Self {
-            name: "Upload",
-            filled_symbol: "▰",
-            empty_symbol: "▱",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Upload"),
+            filled_symbol: Cow::Borrowed("▰"),
+            empty_symbol: Cow::Borrowed("▱"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -461,10 +619,10 @@ impl SliderStyle {
     /// Health bar style (gaming UI)
     pub fn progress_health() -> Self {
         Self {
-            name: "Health",
-            filled_symbol: "▓",
-            empty_symbol: "░",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Health"),
+            filled_symbol: Cow::Borrowed("▓"),
+            empty_symbol: Cow::Borrowed("░"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Red,
             empty_color: Color::Rgb(40, 40, 40),
             handle_color: Color::White,
@@ -475,10 +633,10 @@ impl SliderStyle {
     /// Mana bar style (gaming UI)
     pub fn progress_mana() -> Self {
         Self {
-            name: "Mana",
-            filled_symbol: "▓",
-            empty_symbol: "░",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Mana"),
+            filled_symbol: Cow::Borrowed("▓"),
+            empty_symbol: Cow::Borrowed("░"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Cyan,
             empty_color: Color::Rgb(40, 40, 40),
             handle_color: Color::White,
@@ -489,10 +647,10 @@ impl SliderStyle {
     /// Experience bar style (gaming UI)
     pub fn progress_experience() -> Self {
         Self {
-            name: "Experience",
-            filled_symbol: "━",
-            empty_symbol: "─",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Experience"),
+            filled_symbol: Cow::Borrowed("━"),
+            empty_symbol: Cow::Borrowed("─"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -503,10 +661,10 @@ impl SliderStyle {
     /// Loading bar style
     pub fn progress_loading() -> Self {
         Self {
-            name: "Loading",
-            filled_symbol: "═",
-            empty_symbol: "─",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Loading"),
+            filled_symbol: Cow::Borrowed("═"),
+            empty_symbol: Cow::Borrowed("─"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Magenta,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -517,10 +675,10 @@ impl SliderStyle {
     /// Installation progress bar style
     pub fn progress_installation() -> Self {
         Self {
-            name: "Installation",
-            filled_symbol: "▬",
-            empty_symbol: "▭",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Installation"),
+            filled_symbol: Cow::Borrowed("▬"),
+            empty_symbol: Cow::Borrowed("▭"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::LightGreen,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -531,10 +689,10 @@ impl SliderStyle {
     /// Battery level bar style
     pub fn progress_battery() -> Self {
         Self {
-            name: "Battery",
-            filled_symbol: "■",
-            empty_symbol: "□",
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Battery"),
+            filled_symbol: Cow::Borrowed("■"),
+            empty_symbol: Cow::Borrowed("□"),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::LightYellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -550,10 +708,10 @@ impl SliderStyle {
     /// Vertical slider style - clean vertical lines
     pub fn vertical() -> Self {
         Self {
-            name: "Vertical",
-            filled_symbol: symbols::FILLED_VERTICAL_LINE,
-            empty_symbol: symbols::EMPTY_VERTICAL_LINE,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Vertical"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_VERTICAL_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_VERTICAL_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -564,10 +722,10 @@ impl SliderStyle {
     /// Vertical slider style - bold blocks
     pub fn vertical_blocks() -> Self {
         Self {
-            name: "Vertical Blocks",
-            filled_symbol: symbols::FILLED_BLOCK,
-            empty_symbol: symbols::EMPTY_VERTICAL_BAR,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Vertical Blocks"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BLOCK),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_VERTICAL_BAR),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -578,10 +736,10 @@ impl SliderStyle {
     /// Vertical slider style - shaded gradient
     pub fn vertical_gradient() -> Self {
         Self {
-            name: "Vertical Gradient",
-            filled_symbol: symbols::FILLED_DARK_SHADE,
-            empty_symbol: symbols::FILLED_LIGHT_SHADE,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Vertical Gradient"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_DARK_SHADE),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::Magenta,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -592,10 +750,10 @@ impl SliderStyle {
     /// Vertical slider style - dots/circles
     pub fn vertical_dots() -> Self {
         Self {
-            name: "Vertical Dots",
-            filled_symbol: symbols::FILLED_CIRCLE,
-            empty_symbol: symbols::EMPTY_CIRCLE,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Vertical Dots"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_CIRCLE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_CIRCLE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -606,10 +764,10 @@ impl SliderStyle {
     /// Vertical slider style - squares
     pub fn vertical_squares() -> Self {
         Self {
-            name: "Vertical Squares",
-            filled_symbol: symbols::FILLED_SQUARE,
-            empty_symbol: symbols::EMPTY_SQUARE,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Vertical Squares"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_SQUARE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_SQUARE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -620,10 +778,10 @@ impl SliderStyle {
     /// Vertical slider style - equalizer bars
     pub fn vertical_equalizer() -> Self {
         Self {
-            name: "Equalizer",
-            filled_symbol: symbols::FILLED_VERTICAL_BAR,
-            empty_symbol: symbols::EMPTY_VERTICAL_BAR,
-            handle_symbol: symbols::HANDLE_HORIZONTAL_LINE,
+            name: Cow::Borrowed("Equalizer"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_VERTICAL_BAR),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_VERTICAL_BAR),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_HORIZONTAL_LINE),
             filled_color: Color::LightGreen,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -639,10 +797,10 @@ impl SliderStyle {
     /// Horizontal slider style - clean horizontal lines
     pub fn horizontal() -> Self {
         Self {
-            name: "Horizontal",
-            filled_symbol: symbols::FILLED_HORIZONTAL_LINE,
-            empty_symbol: symbols::EMPTY_HORIZONTAL_LINE,
-            handle_symbol: symbols::HANDLE_VERTICAL_LINE,
+            name: Cow::Borrowed("Horizontal"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_HORIZONTAL_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_HORIZONTAL_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_VERTICAL_LINE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -653,10 +811,10 @@ impl SliderStyle {
     /// Horizontal slider style - thick lines
     pub fn horizontal_thick() -> Self {
         Self {
-            name: "Horizontal Thick",
-            filled_symbol: symbols::FILLED_THICK_LINE,
-            empty_symbol: symbols::EMPTY_THIN_LINE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Thick"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THICK_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Cyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -667,10 +825,10 @@ impl SliderStyle {
     /// Horizontal slider style - bold blocks
     pub fn horizontal_blocks() -> Self {
         Self {
-            name: "Horizontal Blocks",
-            filled_symbol: symbols::FILLED_BLOCK,
-            empty_symbol: symbols::FILLED_LIGHT_SHADE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Blocks"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BLOCK),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Green,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -681,10 +839,10 @@ impl SliderStyle {
     /// Horizontal slider style - shaded gradient
     pub fn horizontal_gradient() -> Self {
         Self {
-            name: "Horizontal Gradient",
-            filled_symbol: symbols::FILLED_DARK_SHADE,
-            empty_symbol: symbols::FILLED_LIGHT_SHADE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Gradient"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_DARK_SHADE),
+            empty_symbol: Cow::Borrowed(symbols::FILLED_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Magenta,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -695,10 +853,10 @@ impl SliderStyle {
     /// Horizontal slider style - dots/circles
     pub fn horizontal_dots() -> Self {
         Self {
-            name: "Horizontal Dots",
-            filled_symbol: symbols::FILLED_CIRCLE,
-            empty_symbol: symbols::EMPTY_CIRCLE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Dots"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_CIRCLE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_CIRCLE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Yellow,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -709,10 +867,10 @@ impl SliderStyle {
     /// Horizontal slider style - squares
     pub fn horizontal_squares() -> Self {
         Self {
-            name: "Horizontal Squares",
-            filled_symbol: symbols::FILLED_SQUARE,
-            empty_symbol: symbols::EMPTY_SQUARE,
-            handle_symbol: symbols::HANDLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Squares"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_SQUARE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_SQUARE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
             filled_color: Color::Blue,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -723,10 +881,10 @@ impl SliderStyle {
     /// Horizontal slider style - double lines
     pub fn horizontal_double() -> Self {
         Self {
-            name: "Horizontal Double",
-            filled_symbol: symbols::FILLED_DOUBLE_LINE,
-            empty_symbol: symbols::EMPTY_THIN_LINE,
-            handle_symbol: symbols::HANDLE_DOUBLE_CIRCLE,
+            name: Cow::Borrowed("Horizontal Double"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_DOUBLE_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_DOUBLE_CIRCLE),
             filled_color: Color::LightCyan,
             empty_color: Color::DarkGray,
             handle_color: Color::White,
@@ -735,8 +893,124 @@ impl SliderStyle {
     }
 }
 
+/// Preset registry
+///
+/// Lists the presets above so they can be offered in a picker and stored by name.
+impl SliderStyle {
+    /// Get all presets as a list
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::SliderStyle;
+    ///
+    /// let styles = SliderStyle::all();
+    /// assert_eq!(styles.len(), 42);
+    /// assert_eq!(styles[0].name, "Default");
+    /// ```
+    pub fn all() -> Vec<SliderStyle> {
+        PRESETS.iter().map(|(_, preset)| preset()).collect()
+    }
+
+    /// Get the presets of a category
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::{SliderStyle, StyleCategory};
+    ///
+    /// let meters = SliderStyle::by_category(StyleCategory::Vertical);
+    /// assert!(meters.iter().any(|style| style.name == "Equalizer"));
+    /// ```
+    pub fn by_category(category: StyleCategory) -> Vec<SliderStyle> {
+        PRESETS
+            .iter()
+            .filter(|(preset_category, _)| *preset_category == category)
+            .map(|(_, preset)| preset())
+            .collect()
+    }
+
+    /// Look up a preset by its display name, ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::SliderStyle;
+    ///
+    /// let style = SliderStyle::by_name("retro").unwrap();
+    /// assert_eq!(style.name, "Retro");
+    /// assert!(SliderStyle::by_name("Unknown").is_none());
+    /// ```
+    pub fn by_name(name: &str) -> Option<SliderStyle> {
+        PRESETS
+            .iter()
+            .map(|(_, preset)| preset())
+            .find(|style| style.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Get the category of a preset, or `None` for a custom style
+    ///
+    /// Presets are recognised by their display name only, so a custom style
+    /// named after one, such as `SliderStyle::custom("Retro")`, reports that
+    /// preset's category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::style::{SliderStyle, StyleCategory};
+    ///
+    /// assert_eq!(
+    ///     SliderStyle::progress_battery().category(),
+    ///     Some(StyleCategory::Progress)
+    /// );
+    /// assert_eq!(SliderStyle::custom("Mine").category(), None);
+    /// ```
+    pub fn category(&self) -> Option<StyleCategory> {
+        PRESETS
+            .iter()
+            .find(|(_, preset)| preset().name == self.name)
+            .map(|(category, _)| *category)
+    }
+}
+
 impl Default for SliderStyle {
     fn default() -> Self {
         Self::default_style()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_presets_round_trip_by_name() {
+        for (category, preset) in PRESETS {
+            let style = preset();
+            let found = SliderStyle::by_name(&style.name).unwrap();
+            assert_eq!(found.name, style.name);
+            assert_eq!(found.filled_symbol, style.filled_symbol);
+            assert_eq!(found.handle_symbol, style.handle_symbol);
+            assert_eq!(style.category(), Some(*category), "{}", style.name);
+        }
+    }
+
+    #[test]
+    fn test_preset_names_unique() {
+        let mut names = HashSet::new();
+        for style in SliderStyle::all() {
+            let name = style.name.to_lowercase();
+            assert!(names.insert(name), "duplicate preset name {}", style.name);
+        }
+        assert_eq!(names.len(), PRESETS.len());
+    }
+
+    #[test]
+    fn test_category_matches_by_name() {
+        assert_eq!(
+            SliderStyle::custom("Retro").category(),
+            SliderStyle::retro().category()
+        );
+    }
+}
@@ -18,6 +18,8 @@
 //!
 //! [`Slider`]: crate::Slider
 
+use std::borrow::Cow;
+
 // ============================================================================
 // FILLED SYMBOLS - Used for the filled portion of the slider
 // ============================================================================
@@ -128,6 +130,9 @@ pub const EMPTY_PROGRESS: &str = "▱";
 /// Empty symbol - braille lower dots
 pub const EMPTY_BRAILLE: &str = "⣀";
 
+/// Empty symbol - braille left dots (for vertical sliders)
+pub const EMPTY_BRAILLE_VERTICAL: &str = "⡇";
+
 /// Empty symbol - tilde/wave
 pub const EMPTY_WAVE: &str = "˜";
 
@@ -273,246 +278,214 @@ pub const HANDLE_HORIZONTAL_LINE: &str = "━";
 /// Handle symbol - vertical line (for horizontal sliders)
 pub const HANDLE_VERTICAL_LINE: &str = "│";
 
+// ============================================================================
+// PARTIAL BLOCK SYMBOLS - Used for sub-cell precision fills
+// ============================================================================
+
+/// Left-aligned partial blocks from one eighth to seven eighths of a cell
+///
+/// Index `n` covers `n + 1` eighths; a full cell is [`FILLED_BLOCK`].
+pub const HORIZONTAL_EIGHTHS: [&str; 7] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// Bottom-aligned partial blocks from one eighth to seven eighths of a cell
+///
+/// Index `n` covers `n + 1` eighths; a full cell is [`FILLED_BLOCK`].
+pub const VERTICAL_EIGHTHS: [&str; 7] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇"];
+
+/// Lower half block - top edge of a thick horizontal track
+pub const LOWER_HALF_BLOCK: &str = "▄";
+
+/// Upper half block - bottom edge of a thick horizontal track
+pub const UPPER_HALF_BLOCK: &str = "▀";
+
+/// Right half block - left edge of a thick vertical track
+pub const RIGHT_HALF_BLOCK: &str = "▐";
+
+/// Left half block - right edge of a thick vertical track
+pub const LEFT_HALF_BLOCK: &str = "▌";
+
 // ============================================================================
 // PREDEFINED STYLE SETS
 // ============================================================================
 
 /// A complete symbol set for a slider style
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Symbols are borrowed for the predefined sets and can be owned for sets
+/// loaded at runtime, such as from a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SymbolSet {
     /// Symbol for the filled portion
-    pub filled: &'static str,
+    pub filled: Cow<'static, str>,
     /// Symbol for the empty portion
-    pub empty: &'static str,
+    pub empty: Cow<'static, str>,
     /// Symbol for the handle
-    pub handle: &'static str,
+    pub handle: Cow<'static, str>,
 }
 
 impl SymbolSet {
     /// Create a new custom symbol set
     pub const fn new(filled: &'static str, empty: &'static str, handle: &'static str) -> Self {
         Self {
-            filled,
-            empty,
-            handle,
+            filled: Cow::Borrowed(filled),
+            empty: Cow::Borrowed(empty),
+            handle: Cow::Borrowed(handle),
+        }
+    }
+
+    /// Create a symbol set from symbols known only at runtime
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::symbols::SymbolSet;
+    ///
+    /// // e.g. read from a config file
+    /// let config = String::from("=,-,|");
+    /// let parts: Vec<&str> = config.split(',').collect();
+    ///
+    /// let set = SymbolSet::owned(parts[0], parts[1], parts[2]);
+    /// assert_eq!(set.handle, "|");
+    /// ```
+    pub fn owned(
+        filled: impl Into<String>,
+        empty: impl Into<String>,
+        handle: impl Into<String>,
+    ) -> Self {
+        Self {
+            filled: Cow::Owned(filled.into()),
+            empty: Cow::Owned(empty.into()),
+            handle: Cow::Owned(handle.into()),
         }
     }
 }
 
 /// Default style - clean and professional
-pub const STYLE_DEFAULT: SymbolSet = SymbolSet {
-    filled: FILLED_THICK_LINE,
-    empty: EMPTY_THIN_LINE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_DEFAULT: SymbolSet =
+    SymbolSet::new(FILLED_THICK_LINE, EMPTY_THIN_LINE, HANDLE_CIRCLE);
 
 /// Block style - bold and solid
-pub const STYLE_BLOCK: SymbolSet = SymbolSet {
-    filled: FILLED_BLOCK,
-    empty: FILLED_LIGHT_SHADE,
-    handle: FILLED_DARK_SHADE,
-};
+pub const STYLE_BLOCK: SymbolSet =
+    SymbolSet::new(FILLED_BLOCK, FILLED_LIGHT_SHADE, FILLED_DARK_SHADE);
 
 /// Dotted style - braille patterns
-pub const STYLE_DOTTED: SymbolSet = SymbolSet {
-    filled: FILLED_BRAILLE,
-    empty: EMPTY_BRAILLE,
-    handle: HANDLE_BLACK_CIRCLE,
-};
+pub const STYLE_DOTTED: SymbolSet =
+    SymbolSet::new(FILLED_BRAILLE, EMPTY_BRAILLE, HANDLE_BLACK_CIRCLE);
 
 /// Minimal style - clean and subtle
-pub const STYLE_MINIMAL: SymbolSet = SymbolSet {
-    filled: FILLED_THIN_LINE,
-    empty: EMPTY_SPACE,
-    handle: HANDLE_VERTICAL_BAR,
-};
+pub const STYLE_MINIMAL: SymbolSet =
+    SymbolSet::new(FILLED_THIN_LINE, EMPTY_SPACE, HANDLE_VERTICAL_BAR);
 
 /// Double line style - formal appearance
-pub const STYLE_DOUBLE_LINE: SymbolSet = SymbolSet {
-    filled: FILLED_DOUBLE_LINE,
-    empty: EMPTY_THIN_LINE,
-    handle: HANDLE_DOUBLE_CIRCLE,
-};
+pub const STYLE_DOUBLE_LINE: SymbolSet =
+    SymbolSet::new(FILLED_DOUBLE_LINE, EMPTY_THIN_LINE, HANDLE_DOUBLE_CIRCLE);
 
 /// Wave style - fluid appearance
-pub const STYLE_WAVE: SymbolSet = SymbolSet {
-    filled: FILLED_WAVE,
-    empty: EMPTY_WAVE,
-    handle: HANDLE_DOUBLE_DIAMOND,
-};
+pub const STYLE_WAVE: SymbolSet = SymbolSet::new(FILLED_WAVE, EMPTY_WAVE, HANDLE_DOUBLE_DIAMOND);
 
 /// Progress style - progress bar look
-pub const STYLE_PROGRESS: SymbolSet = SymbolSet {
-    filled: FILLED_PROGRESS,
-    empty: EMPTY_PROGRESS,
-    handle: HANDLE_TRIANGLE_RIGHT,
-};
+pub const STYLE_PROGRESS: SymbolSet =
+    SymbolSet::new(FILLED_PROGRESS, EMPTY_PROGRESS, HANDLE_TRIANGLE_RIGHT);
 
 /// Thick style - bold appearance
-pub const STYLE_THICK: SymbolSet = SymbolSet {
-    filled: FILLED_BAR,
-    empty: FILLED_BAR,
-    handle: HANDLE_SQUARE,
-};
+pub const STYLE_THICK: SymbolSet = SymbolSet::new(FILLED_BAR, FILLED_BAR, HANDLE_SQUARE);
 
 /// Gradient style - shaded effect
-pub const STYLE_GRADIENT: SymbolSet = SymbolSet {
-    filled: FILLED_DARK_SHADE,
-    empty: FILLED_LIGHT_SHADE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_GRADIENT: SymbolSet =
+    SymbolSet::new(FILLED_DARK_SHADE, FILLED_LIGHT_SHADE, HANDLE_CIRCLE);
 
 /// Rounded style - soft appearance
-pub const STYLE_ROUNDED: SymbolSet = SymbolSet {
-    filled: FILLED_THIN_LINE,
-    empty: EMPTY_DASHED,
-    handle: HANDLE_LARGE_CIRCLE,
-};
+pub const STYLE_ROUNDED: SymbolSet =
+    SymbolSet::new(FILLED_THIN_LINE, EMPTY_DASHED, HANDLE_LARGE_CIRCLE);
 
 /// Retro style - old-school ASCII
-pub const STYLE_RETRO: SymbolSet = SymbolSet {
-    filled: FILLED_HASH,
-    empty: EMPTY_DOT,
-    handle: HANDLE_AT,
-};
+pub const STYLE_RETRO: SymbolSet = SymbolSet::new(FILLED_HASH, EMPTY_DOT, HANDLE_AT);
 
 /// Neon style - modern look
-pub const STYLE_NEON: SymbolSet = SymbolSet {
-    filled: FILLED_LOWER_BAR,
-    empty: EMPTY_LOWER_BAR,
-    handle: HANDLE_LOWER_BAR,
-};
+pub const STYLE_NEON: SymbolSet =
+    SymbolSet::new(FILLED_LOWER_BAR, EMPTY_LOWER_BAR, HANDLE_LOWER_BAR);
 
 /// Diamond style - elegant look
-pub const STYLE_DIAMOND: SymbolSet = SymbolSet {
-    filled: FILLED_DIAMOND,
-    empty: EMPTY_DIAMOND,
-    handle: HANDLE_DOUBLE_DIAMOND,
-};
+pub const STYLE_DIAMOND: SymbolSet =
+    SymbolSet::new(FILLED_DIAMOND, EMPTY_DIAMOND, HANDLE_DOUBLE_DIAMOND);
 
 /// Star style - decorative look
-pub const STYLE_STAR: SymbolSet = SymbolSet {
-    filled: FILLED_STAR,
-    empty: EMPTY_STAR,
-    handle: HANDLE_FILLED_STAR,
-};
+pub const STYLE_STAR: SymbolSet = SymbolSet::new(FILLED_STAR, EMPTY_STAR, HANDLE_FILLED_STAR);
 
 /// Arrow style - directional look
-pub const STYLE_ARROW: SymbolSet = SymbolSet {
-    filled: FILLED_BAR,
-    empty: EMPTY_BAR_OUTLINE,
-    handle: HANDLE_DIAMOND,
-};
+pub const STYLE_ARROW: SymbolSet = SymbolSet::new(FILLED_BAR, EMPTY_BAR_OUTLINE, HANDLE_DIAMOND);
 
 /// Segmented style - discrete segments with dashes
-pub const STYLE_SEGMENTED: SymbolSet = SymbolSet {
-    filled: FILLED_SEGMENT,
-    empty: EMPTY_SPACE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_SEGMENTED: SymbolSet = SymbolSet::new(FILLED_SEGMENT, EMPTY_SPACE, HANDLE_CIRCLE);
 
 /// Segmented blocks style - vertical bars
-pub const STYLE_SEGMENTED_BLOCKS: SymbolSet = SymbolSet {
-    filled: FILLED_VERTICAL_BAR,
-    empty: EMPTY_VERTICAL_BAR,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_SEGMENTED_BLOCKS: SymbolSet =
+    SymbolSet::new(FILLED_VERTICAL_BAR, EMPTY_VERTICAL_BAR, HANDLE_CIRCLE);
 
 /// Segmented dots style - filled and empty circles
-pub const STYLE_SEGMENTED_DOTS: SymbolSet = SymbolSet {
-    filled: FILLED_CIRCLE,
-    empty: EMPTY_CIRCLE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_SEGMENTED_DOTS: SymbolSet =
+    SymbolSet::new(FILLED_CIRCLE, EMPTY_CIRCLE, HANDLE_CIRCLE);
 
 /// Segmented squares style - filled and empty squares
-pub const STYLE_SEGMENTED_SQUARES: SymbolSet = SymbolSet {
-    filled: FILLED_SQUARE,
-    empty: EMPTY_SQUARE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_SEGMENTED_SQUARES: SymbolSet =
+    SymbolSet::new(FILLED_SQUARE, EMPTY_SQUARE, HANDLE_CIRCLE);
 
 // ============================================================================
 // VERTICAL SLIDER STYLES
 // ============================================================================
 
 /// Vertical slider style - clean vertical lines
-pub const STYLE_VERTICAL: SymbolSet = SymbolSet {
-    filled: FILLED_VERTICAL_LINE,
-    empty: EMPTY_VERTICAL_LINE,
-    handle: HANDLE_HORIZONTAL_LINE,
-};
+pub const STYLE_VERTICAL: SymbolSet = SymbolSet::new(
+    FILLED_VERTICAL_LINE,
+    EMPTY_VERTICAL_LINE,
+    HANDLE_HORIZONTAL_LINE,
+);
 
 /// Vertical slider style - bold blocks
-pub const STYLE_VERTICAL_BLOCKS: SymbolSet = SymbolSet {
-    filled: FILLED_BLOCK,
-    empty: EMPTY_VERTICAL_BAR,
-    handle: HANDLE_HORIZONTAL_LINE,
-};
+pub const STYLE_VERTICAL_BLOCKS: SymbolSet =
+    SymbolSet::new(FILLED_BLOCK, EMPTY_VERTICAL_BAR, HANDLE_HORIZONTAL_LINE);
 
 /// Vertical slider style - shaded gradient
-pub const STYLE_VERTICAL_GRADIENT: SymbolSet = SymbolSet {
-    filled: FILLED_DARK_SHADE,
-    empty: FILLED_LIGHT_SHADE,
-    handle: HANDLE_HORIZONTAL_LINE,
-};
+pub const STYLE_VERTICAL_GRADIENT: SymbolSet = SymbolSet::new(
+    FILLED_DARK_SHADE,
+    FILLED_LIGHT_SHADE,
+    HANDLE_HORIZONTAL_LINE,
+);
 
 /// Vertical slider style - dots/circles
-pub const STYLE_VERTICAL_DOTS: SymbolSet = SymbolSet {
-    filled: FILLED_CIRCLE,
-    empty: EMPTY_CIRCLE,
-    handle: HANDLE_HORIZONTAL_LINE,
-};
+pub const STYLE_VERTICAL_DOTS: SymbolSet =
+    SymbolSet::new(FILLED_CIRCLE, EMPTY_CIRCLE, HANDLE_HORIZONTAL_LINE);
 
 /// Vertical slider style - squares
-pub const STYLE_VERTICAL_SQUARES: SymbolSet = SymbolSet {
-    filled: FILLED_SQUARE,
-    empty: EMPTY_SQUARE,
-    handle: HANDLE_HORIZONTAL_LINE,
-};
+pub const STYLE_VERTICAL_SQUARES: SymbolSet =
+    SymbolSet::new(FILLED_SQUARE, EMPTY_SQUARE, HANDLE_HORIZONTAL_LINE);
 
 // ============================================================================
 // HORIZONTAL SLIDER STYLES
 // ============================================================================
 
 /// Horizontal slider style - clean horizontal lines
-pub const STYLE_HORIZONTAL: SymbolSet = SymbolSet {
-    filled: FILLED_HORIZONTAL_LINE,
-    empty: EMPTY_HORIZONTAL_LINE,
-    handle: HANDLE_VERTICAL_LINE,
-};
+pub const STYLE_HORIZONTAL: SymbolSet = SymbolSet::new(
+    FILLED_HORIZONTAL_LINE,
+    EMPTY_HORIZONTAL_LINE,
+    HANDLE_VERTICAL_LINE,
+);
 
 /// Horizontal slider style - thick lines
-pub const STYLE_HORIZONTAL_THICK: SymbolSet = SymbolSet {
-    filled: FILLED_THICK_LINE,
-    empty: EMPTY_THIN_LINE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_HORIZONTAL_THICK: SymbolSet =
+    SymbolSet::new(FILLED_THICK_LINE, EMPTY_THIN_LINE, HANDLE_CIRCLE);
 
 /// Horizontal slider style - bold blocks
-pub const STYLE_HORIZONTAL_BLOCKS: SymbolSet = SymbolSet {
-    filled: FILLED_BLOCK,
-    empty: FILLED_LIGHT_SHADE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_HORIZONTAL_BLOCKS: SymbolSet =
+    SymbolSet::new(FILLED_BLOCK, FILLED_LIGHT_SHADE, HANDLE_CIRCLE);
 
 /// Horizontal slider style - shaded gradient
-pub const STYLE_HORIZONTAL_GRADIENT: SymbolSet = SymbolSet {
-    filled: FILLED_DARK_SHADE,
-    empty: FILLED_LIGHT_SHADE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_HORIZONTAL_GRADIENT: SymbolSet =
+    SymbolSet::new(FILLED_DARK_SHADE, FILLED_LIGHT_SHADE, HANDLE_CIRCLE);
 
 /// Horizontal slider style - dots/circles
-pub const STYLE_HORIZONTAL_DOTS: SymbolSet = SymbolSet {
-    filled: FILLED_CIRCLE,
-    empty: EMPTY_CIRCLE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_HORIZONTAL_DOTS: SymbolSet =
+    SymbolSet::new(FILLED_CIRCLE, EMPTY_CIRCLE, HANDLE_CIRCLE);
 
 /// Horizontal slider style - squares
-pub const STYLE_HORIZONTAL_SQUARES: SymbolSet = SymbolSet {
-    filled: FILLED_SQUARE,
-    empty: EMPTY_SQUARE,
-    handle: HANDLE_CIRCLE,
-};
+pub const STYLE_HORIZONTAL_SQUARES: SymbolSet =
+    SymbolSet::new(FILLED_SQUARE, EMPTY_SQUARE, HANDLE_CIRCLE);
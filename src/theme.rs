@@ -0,0 +1,328 @@
+//! Slider theme module
+//!
+//! This module provides [`SliderTheme`], a shared palette of symbols and text
+//! styles for the normal, focused and disabled looks of a slider. Applying one
+//! theme to every slider keeps screens consistent, and switching the theme at
+//! runtime re-skins them all on the next frame.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tui_slider::theme::SliderTheme;
+//! use tui_slider::Slider;
+//!
+//! let theme = SliderTheme::dark();
+//!
+//! let volume = Slider::new(70.0, 0.0, 100.0)
+//!     .label("Volume")
+//!     .theme(&theme)
+//!     .focused(true);
+//! let balance = Slider::new(50.0, 0.0, 100.0)
+//!     .label("Balance")
+//!     .theme(&theme)
+//!     .disabled(true);
+//! ```
+
+use crate::symbols;
+use ratatui::style::{Color, Modifier, Style};
+use std::borrow::Cow;
+
+/// Styles for each part of a slider
+///
+/// Used as is for the normal look. For the focused and disabled looks the
+/// styles are patched over the normal ones, so fields left unset keep their
+/// normal value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemeStyles {
+    /// Style of the filled portion of the bar
+    pub filled: Style,
+    /// Style of the empty portion of the bar
+    pub empty: Style,
+    /// Style of the handle
+    pub handle: Style,
+    /// Style of the label
+    pub label: Style,
+    /// Style of the value display
+    pub value: Style,
+}
+
+impl ThemeStyles {
+    /// Creates a set of styles that change nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the style of the filled portion of the bar
+    pub fn filled(mut self, style: Style) -> Self {
+        self.filled = style;
+        self
+    }
+
+    /// Sets the style of the empty portion of the bar
+    pub fn empty(mut self, style: Style) -> Self {
+        self.empty = style;
+        self
+    }
+
+    /// Sets the style of the handle
+    pub fn handle(mut self, style: Style) -> Self {
+        self.handle = style;
+        self
+    }
+
+    /// Sets the style of the label
+    pub fn label(mut self, style: Style) -> Self {
+        self.label = style;
+        self
+    }
+
+    /// Sets the style of the value display
+    pub fn value(mut self, style: Style) -> Self {
+        self.value = style;
+        self
+    }
+
+    /// Sets the same style on every part
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::theme::ThemeStyles;
+    ///
+    /// let greyed_out = ThemeStyles::all(Style::new().fg(Color::DarkGray));
+    /// ```
+    pub fn all(style: Style) -> Self {
+        Self {
+            filled: style,
+            empty: style,
+            handle: style,
+            label: style,
+            value: style,
+        }
+    }
+
+    /// Returns these styles with `overlay` patched over them
+    pub(crate) fn patch(self, overlay: ThemeStyles) -> Self {
+        Self {
+            filled: self.filled.patch(overlay.filled),
+            empty: self.empty.patch(overlay.empty),
+            handle: self.handle.patch(overlay.handle),
+            label: self.label.patch(overlay.label),
+            value: self.value.patch(overlay.value),
+        }
+    }
+}
+
+/// Symbols and styles shared by a group of sliders
+///
+/// Apply it with [`Slider::theme`](crate::Slider::theme) and pick the look with
+/// [`Slider::focused`](crate::Slider::focused) and
+/// [`Slider::disabled`](crate::Slider::disabled).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SliderTheme {
+    /// Display name for the theme
+    pub name: Cow<'static, str>,
+    /// Symbol for the filled portion
+    pub filled_symbol: Cow<'static, str>,
+    /// Symbol for the empty portion
+    pub empty_symbol: Cow<'static, str>,
+    /// Symbol for the slider handle/thumb
+    pub handle_symbol: Cow<'static, str>,
+    /// Styles of an idle slider
+    pub normal: ThemeStyles,
+    /// Styles patched over the normal ones while focused
+    pub focused: ThemeStyles,
+    /// Styles patched over the normal ones while disabled
+    pub disabled: ThemeStyles,
+}
+
+impl SliderTheme {
+    /// Dark theme - cyan on a dark background
+    pub fn dark() -> Self {
+        Self {
+            name: Cow::Borrowed("Dark"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THICK_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
+            normal: ThemeStyles {
+                filled: Style::new().fg(Color::Cyan),
+                empty: Style::new().fg(Color::DarkGray),
+                handle: Style::new().fg(Color::White),
+                label: Style::new().fg(Color::White),
+                value: Style::new().fg(Color::Gray),
+            },
+            focused: ThemeStyles {
+                filled: Style::new().fg(Color::LightCyan),
+                handle: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                label: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ..ThemeStyles::new()
+            },
+            disabled: ThemeStyles::all(
+                Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+            ),
+        }
+    }
+
+    /// Light theme - blue on a light background
+    pub fn light() -> Self {
+        Self {
+            name: Cow::Borrowed("Light"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_THICK_LINE),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_THIN_LINE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_CIRCLE),
+            normal: ThemeStyles {
+                filled: Style::new().fg(Color::Blue),
+                empty: Style::new().fg(Color::Gray),
+                handle: Style::new().fg(Color::Black),
+                label: Style::new().fg(Color::Black),
+                value: Style::new().fg(Color::DarkGray),
+            },
+            focused: ThemeStyles {
+                filled: Style::new().fg(Color::LightBlue),
+                handle: Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                label: Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                ..ThemeStyles::new()
+            },
+            disabled: ThemeStyles::all(Style::new().fg(Color::Gray).add_modifier(Modifier::DIM)),
+        }
+    }
+
+    /// High-contrast theme - solid blocks and bold text, readable without color
+    pub fn high_contrast() -> Self {
+        Self {
+            name: Cow::Borrowed("High Contrast"),
+            filled_symbol: Cow::Borrowed(symbols::FILLED_BLOCK),
+            empty_symbol: Cow::Borrowed(symbols::EMPTY_LIGHT_SHADE),
+            handle_symbol: Cow::Borrowed(symbols::HANDLE_MEDIUM_BLOCK),
+            normal: ThemeStyles {
+                filled: Style::new().fg(Color::White),
+                empty: Style::new().fg(Color::Gray),
+                handle: Style::new().fg(Color::Yellow),
+                label: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+                value: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+            },
+            focused: ThemeStyles {
+                handle: Style::new().add_modifier(Modifier::REVERSED),
+                label: Style::new()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::UNDERLINED),
+                value: Style::new().fg(Color::Yellow),
+                ..ThemeStyles::new()
+            },
+            disabled: ThemeStyles {
+                filled: Style::new().fg(Color::Gray),
+                empty: Style::new().fg(Color::DarkGray),
+                handle: Style::new().fg(Color::Gray),
+                label: Style::new()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::CROSSED_OUT),
+                value: Style::new().fg(Color::Gray),
+            },
+        }
+    }
+
+    /// Create a custom theme starting from the dark theme
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_slider::symbols;
+    /// use tui_slider::theme::{SliderTheme, ThemeStyles};
+    ///
+    /// let theme = SliderTheme::custom("Forest")
+    ///     .filled_symbol(symbols::FILLED_BLOCK)
+    ///     .normal(
+    ///         ThemeStyles::new()
+    ///             .filled(Style::new().fg(Color::Green))
+    ///             .empty(Style::new().fg(Color::DarkGray)),
+    ///     )
+    ///     .focused(ThemeStyles::new().handle(Style::new().fg(Color::LightGreen)));
+    /// ```
+    pub fn custom(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::dark()
+        }
+    }
+
+    /// Set the filled symbol for the custom theme
+    pub fn filled_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.filled_symbol = symbol.into();
+        self
+    }
+
+    /// Set the empty symbol for the custom theme
+    pub fn empty_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.empty_symbol = symbol.into();
+        self
+    }
+
+    /// Set the handle symbol for the custom theme
+    pub fn handle_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.handle_symbol = symbol.into();
+        self
+    }
+
+    /// Set the styles of an idle slider
+    pub fn normal(mut self, styles: ThemeStyles) -> Self {
+        self.normal = styles;
+        self
+    }
+
+    /// Set the styles patched over the normal ones while focused
+    pub fn focused(mut self, styles: ThemeStyles) -> Self {
+        self.focused = styles;
+        self
+    }
+
+    /// Set the styles patched over the normal ones while disabled
+    pub fn disabled(mut self, styles: ThemeStyles) -> Self {
+        self.disabled = styles;
+        self
+    }
+
+    /// Returns the styles to patch over the normal ones for a slider in the
+    /// given state
+    ///
+    /// Disabled takes precedence over focused.
+    pub(crate) fn overlay(&self, focused: bool, disabled: bool) -> Option<ThemeStyles> {
+        if disabled {
+            Some(self.disabled)
+        } else if focused {
+            Some(self.focused)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for SliderTheme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_keeps_unset_fields() {
+        let theme = SliderTheme::dark();
+        let styles = theme.normal.patch(theme.focused);
+        assert_eq!(styles.handle.fg, Some(Color::Yellow));
+        assert!(styles.handle.add_modifier.contains(Modifier::BOLD));
+        // Not part of the focused styles, so unchanged
+        assert_eq!(styles.empty, theme.normal.empty);
+    }
+
+    #[test]
+    fn test_disabled_wins_over_focused() {
+        let theme = SliderTheme::light();
+        assert_eq!(theme.overlay(true, true), Some(theme.disabled));
+        assert_eq!(theme.overlay(true, false), Some(theme.focused));
+        assert_eq!(theme.overlay(false, false), None);
+    }
+}
@@ -0,0 +1,479 @@
+//! Tick mark module
+//!
+//! This module provides a ruler drawn next to the slider track, with major and
+//! minor tick marks and labels for the major ticks.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tui_slider::tick::{TickPlacement, TickSpacing, Ticks};
+//! use tui_slider::Slider;
+//!
+//! // 0 25 50 75 100 below the bar, with a minor tick every 5 steps
+//! let slider = Slider::new(40.0, 0.0, 100.0).ticks(
+//!     Ticks::new()
+//!         .major(TickSpacing::Interval(25.0))
+//!         .minor(TickSpacing::Steps(5))
+//!         .placement(TickPlacement::After),
+//! );
+//! ```
+
+use crate::format::{FormatContext, SharedFormatter, ValueFormatter};
+use ratatui::style::Color;
+use std::fmt;
+use unicode_width::UnicodeWidthStr;
+
+/// Upper bound on the number of ticks generated for one spacing
+///
+/// Guards against tiny intervals on huge ranges; spacings that would exceed it
+/// produce no ticks.
+const MAX_TICKS: usize = 1024;
+
+/// Default number of major divisions of the range
+const DEFAULT_DIVISIONS: u32 = 4;
+
+/// Distance between ticks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TickSpacing {
+    /// A tick every fixed amount of value, starting at the minimum
+    Interval(f64),
+    /// A tick every given number of slider steps
+    Steps(u32),
+    /// The range divided into the given number of equal parts
+    Divisions(u32),
+}
+
+impl TickSpacing {
+    /// Resolves the spacing to a value interval for the given range and step
+    pub fn interval(&self, range: f64, step: f64) -> f64 {
+        match *self {
+            Self::Interval(interval) => interval,
+            Self::Steps(steps) => steps as f64 * step,
+            Self::Divisions(divisions) => range / divisions as f64,
+        }
+    }
+}
+
+/// Which side of the track the ruler is drawn on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickPlacement {
+    /// Above a horizontal track or left of a vertical one
+    Before,
+    /// Below a horizontal track or right of a vertical one
+    #[default]
+    After,
+}
+
+/// Tick mark and scale label configuration
+///
+/// Labels are only drawn for major ticks. Labels that would overlap a label
+/// already placed are skipped; the minimum and maximum are placed first.
+#[derive(Clone)]
+pub struct Ticks {
+    /// Spacing of the major ticks
+    major: TickSpacing,
+    /// Spacing of the minor ticks
+    minor: Option<TickSpacing>,
+    /// Side of the track the ruler is drawn on
+    placement: TickPlacement,
+    /// Whether to draw labels for the major ticks
+    show_labels: bool,
+    /// Custom label formatter
+    formatter: Option<SharedFormatter>,
+    /// Symbol override for major ticks
+    major_symbol: Option<String>,
+    /// Symbol override for minor ticks
+    minor_symbol: Option<String>,
+    /// Color of tick marks and labels
+    color: Color,
+}
+
+impl Ticks {
+    /// Creates a ruler dividing the range into four major parts, with labels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::Ticks;
+    /// use tui_slider::Slider;
+    ///
+    /// let slider = Slider::new(50.0, 0.0, 100.0).ticks(Ticks::new());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            major: TickSpacing::Divisions(DEFAULT_DIVISIONS),
+            minor: None,
+            placement: TickPlacement::default(),
+            show_labels: true,
+            formatter: None,
+            major_symbol: None,
+            minor_symbol: None,
+            color: Color::Gray,
+        }
+    }
+
+    /// Sets the spacing of the major ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::{TickSpacing, Ticks};
+    ///
+    /// let ticks = Ticks::new().major(TickSpacing::Interval(10.0));
+    /// ```
+    pub fn major(mut self, spacing: TickSpacing) -> Self {
+        self.major = spacing;
+        self
+    }
+
+    /// Sets the spacing of the minor ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::{TickSpacing, Ticks};
+    ///
+    /// // A minor tick on every step
+    /// let ticks = Ticks::new().minor(TickSpacing::Steps(1));
+    /// ```
+    pub fn minor(mut self, spacing: TickSpacing) -> Self {
+        self.minor = Some(spacing);
+        self
+    }
+
+    /// Sets the side of the track the ruler is drawn on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::{TickPlacement, Ticks};
+    ///
+    /// let ticks = Ticks::new().placement(TickPlacement::Before);
+    /// ```
+    pub fn placement(mut self, placement: TickPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether labels are drawn for the major ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::Ticks;
+    ///
+    /// let ticks = Ticks::new().labels(false);
+    /// ```
+    pub fn labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Sets the label formatter
+    ///
+    /// Accepts a closure or any [`ValueFormatter`], so labels can match the
+    /// slider's value display. By default labels use as many decimals as the
+    /// major interval needs, written with the slider's number format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::format::Percent;
+    /// use tui_slider::tick::Ticks;
+    ///
+    /// let ticks = Ticks::new().formatter(|value: f64| format!("{value:.0}%"));
+    /// let ticks = Ticks::new().formatter(Percent::new(0));
+    /// ```
+    pub fn formatter<F: ValueFormatter + 'static>(mut self, formatter: F) -> Self {
+        self.formatter = Some(SharedFormatter::new(formatter));
+        self
+    }
+
+    /// Sets the symbol drawn for major ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::Ticks;
+    ///
+    /// let ticks = Ticks::new().major_symbol("┴");
+    /// ```
+    pub fn major_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.major_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets the symbol drawn for minor ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tui_slider::tick::Ticks;
+    ///
+    /// let ticks = Ticks::new().minor_symbol("·");
+    /// ```
+    pub fn minor_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.minor_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets the color of tick marks and labels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::tick::Ticks;
+    ///
+    /// let ticks = Ticks::new().color(Color::DarkGray);
+    /// ```
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns the side of the track the ruler is drawn on
+    pub(crate) fn side(&self) -> TickPlacement {
+        self.placement
+    }
+
+    /// Returns the color of tick marks and labels
+    pub(crate) fn mark_color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns true if labels are drawn
+    pub(crate) fn has_labels(&self) -> bool {
+        self.show_labels
+    }
+
+    /// Returns the major and minor tick symbols for a track orientation
+    pub(crate) fn symbols(&self, horizontal: bool) -> (&str, &str) {
+        let (major, minor) = match (horizontal, self.placement) {
+            (true, TickPlacement::After) => ("│", "╵"),
+            (true, TickPlacement::Before) => ("│", "╷"),
+            (false, TickPlacement::After) => ("─", "╴"),
+            (false, TickPlacement::Before) => ("─", "╶"),
+        };
+        (
+            self.major_symbol.as_deref().unwrap_or(major),
+            self.minor_symbol.as_deref().unwrap_or(minor),
+        )
+    }
+
+    /// Returns the major tick values with their labels
+    pub(crate) fn major_ticks(&self, context: &FormatContext, step: f64) -> Vec<(f64, String)> {
+        let (min, max) = (context.min(), context.max());
+        let interval = self.major.interval(max - min, step);
+        let decimals = decimals_for(interval);
+        tick_values(min, max, interval, true)
+            .into_iter()
+            .map(|value| {
+                let label = match &self.formatter {
+                    Some(formatter) => formatter.format(value, context),
+                    None => context.format_number(value, decimals),
+                };
+                (value, label)
+            })
+            .collect()
+    }
+
+    /// Returns the minor tick values
+    pub(crate) fn minor_ticks(&self, min: f64, max: f64, step: f64) -> Vec<f64> {
+        self.minor
+            .map(|minor| tick_values(min, max, minor.interval(max - min, step), false))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for Ticks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Ticks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ticks")
+            .field("major", &self.major)
+            .field("minor", &self.minor)
+            .field("placement", &self.placement)
+            .field("show_labels", &self.show_labels)
+            .field("formatter", &self.formatter.as_ref().map(|_| ".."))
+            .field("major_symbol", &self.major_symbol)
+            .field("minor_symbol", &self.minor_symbol)
+            .field("color", &self.color)
+            .finish()
+    }
+}
+
+/// Returns the tick values from `min` to `max` at the given interval
+///
+/// With `include_max` the maximum gets a tick even when the interval does not
+/// divide the range.
+fn tick_values(min: f64, max: f64, interval: f64, include_max: bool) -> Vec<f64> {
+    let range = max - min;
+    if !(interval.is_finite() && interval > 0.0 && range >= 0.0) {
+        return Vec::new();
+    }
+    // Tolerate rounding so that e.g. 0.1 steps reach the maximum
+    let count = (range / interval + 1e-9).floor();
+    if count >= MAX_TICKS as f64 {
+        return Vec::new();
+    }
+
+    let mut values: Vec<f64> = (0..=count as usize)
+        .map(|i| min + i as f64 * interval)
+        .collect();
+    let reaches_max = values
+        .last()
+        .is_some_and(|last| (max - last).abs() <= interval * 1e-9);
+    if include_max && !reaches_max {
+        values.push(max);
+    }
+    values
+}
+
+/// Returns the number of decimals needed to show multiples of the interval
+fn decimals_for(interval: f64) -> usize {
+    (0..6)
+        .find(|&decimals| {
+            let scaled = interval * 10f64.powi(decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-6
+        })
+        .unwrap_or(6)
+}
+
+/// Returns the cell of a value along a track of `cells` cells, from the start
+pub(crate) fn tick_cell(value: f64, min: f64, max: f64, cells: u16) -> u16 {
+    if max <= min || cells == 0 {
+        return 0;
+    }
+    let percentage = ((value - min) / (max - min)).clamp(0.0, 1.0);
+    (percentage * (cells - 1) as f64).round() as u16
+}
+
+/// Places labels centered on their cells along a line of `len` cells
+///
+/// Labels are kept within the line and placed with at least one blank cell
+/// between them. The first and last labels are placed before the others, and any
+/// label that would overlap one already placed is dropped. Returns the start cell
+/// of every placed label.
+pub(crate) fn place_labels(labels: &[(u16, &str)], len: u16) -> Vec<(u16, usize)> {
+    let mut order: Vec<usize> = (0..labels.len()).collect();
+    if labels.len() > 1 {
+        // Ends first, then the rest from the start
+        order.swap(1, labels.len() - 1);
+        order[2..].sort_unstable();
+    }
+
+    let mut placed: Vec<(u16, u16, usize)> = Vec::new();
+    for index in order {
+        let (center, text) = labels[index];
+        let width = text.width() as u16;
+        if width == 0 || width > len {
+            continue;
+        }
+        let start = center.saturating_sub(width / 2).min(len - width);
+        let end = start + width;
+        let overlaps = placed
+            .iter()
+            .any(|&(other_start, other_end, _)| start < other_end + 1 && other_start < end + 1);
+        if !overlaps {
+            placed.push((start, end, index));
+        }
+    }
+
+    placed.sort_unstable_by_key(|&(start, _, _)| start);
+    placed
+        .into_iter()
+        .map(|(start, _, index)| (start, index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spacing_interval() {
+        assert_eq!(TickSpacing::Interval(5.0).interval(100.0, 1.0), 5.0);
+        assert_eq!(TickSpacing::Steps(4).interval(100.0, 2.5), 10.0);
+        assert_eq!(TickSpacing::Divisions(4).interval(100.0, 1.0), 25.0);
+    }
+
+    #[test]
+    fn test_tick_values() {
+        assert_eq!(
+            tick_values(0.0, 100.0, 25.0, true),
+            vec![0.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(tick_values(0.0, 10.0, 4.0, true), vec![0.0, 4.0, 8.0, 10.0]);
+        assert_eq!(tick_values(0.0, 10.0, 4.0, false), vec![0.0, 4.0, 8.0]);
+        assert_eq!(tick_values(0.0, 1.0, 0.1, true).len(), 11);
+        assert!(tick_values(0.0, 1e9, 1e-3, true).is_empty());
+        assert!(tick_values(0.0, 10.0, 0.0, true).is_empty());
+    }
+
+    #[test]
+    fn test_default_labels() {
+        let ticks = Ticks::new();
+        let labels: Vec<String> = ticks
+            .major_ticks(&FormatContext::new(0.0, 100.0), 1.0)
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect();
+        assert_eq!(labels, vec!["0", "25", "50", "75", "100"]);
+
+        let ticks = Ticks::new().major(TickSpacing::Interval(0.5));
+        let labels: Vec<String> = ticks
+            .major_ticks(&FormatContext::new(0.0, 1.0), 0.1)
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect();
+        assert_eq!(labels, vec!["0.0", "0.5", "1.0"]);
+    }
+
+    #[test]
+    fn test_custom_formatter() {
+        let ticks = Ticks::new().formatter(|value| format!("{value}%"));
+        assert_eq!(
+            ticks.major_ticks(&FormatContext::new(0.0, 100.0), 1.0)[1].1,
+            "25%"
+        );
+    }
+
+    #[test]
+    fn test_minor_ticks_from_steps() {
+        let ticks = Ticks::new().minor(TickSpacing::Steps(2));
+        assert_eq!(ticks.minor_ticks(0.0, 10.0, 2.5), vec![0.0, 5.0, 10.0]);
+        assert!(Ticks::new().minor_ticks(0.0, 10.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_tick_cell() {
+        assert_eq!(tick_cell(0.0, 0.0, 100.0, 21), 0);
+        assert_eq!(tick_cell(50.0, 0.0, 100.0, 21), 10);
+        assert_eq!(tick_cell(100.0, 0.0, 100.0, 21), 20);
+    }
+
+    #[test]
+    fn test_place_labels_all_fit() {
+        let labels = [(0, "0"), (10, "50"), (20, "100")];
+        assert_eq!(place_labels(&labels, 21), vec![(0, 0), (9, 1), (18, 2)]);
+    }
+
+    #[test]
+    fn test_place_labels_skips_collisions() {
+        // Too narrow for every label: the ends win
+        let labels = [(0, "0"), (2, "25"), (5, "50"), (7, "75"), (9, "100")];
+        let placed = place_labels(&labels, 10);
+        assert_eq!(placed, vec![(0, 0), (4, 2), (7, 4)]);
+
+        // No two placed labels touch
+        for pair in placed.windows(2) {
+            let (start, index) = pair[0];
+            assert!(start + (labels[index].1.width() as u16) < pair[1].0);
+        }
+    }
+}
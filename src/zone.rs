@@ -0,0 +1,194 @@
+//! Color zone module
+//!
+//! This module provides value zones that change the color, and optionally the
+//! symbol, of the filled track by region. Typical uses are CPU, temperature and
+//! level meters that turn red above a threshold.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::style::Color;
+//! use tui_slider::zone::{Zone, ZoneMode};
+//! use tui_slider::Slider;
+//!
+//! let slider = Slider::new(90.0, 0.0, 100.0)
+//!     .zones([
+//!         Zone::new(0.0, 60.0, Color::Green),
+//!         Zone::new(60.0, 80.0, Color::Yellow),
+//!         Zone::new(80.0, 100.0, Color::Red),
+//!     ])
+//!     .zone_mode(ZoneMode::PerCell);
+//! ```
+
+use crate::state::SliderState;
+use ratatui::style::Color;
+
+/// How zones are applied to the filled track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZoneMode {
+    /// Each filled cell takes the zone its own position falls in
+    #[default]
+    PerCell,
+    /// The whole fill takes the zone the current value falls in
+    WholeFill,
+}
+
+/// A range of values drawn with its own color and optional symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    /// Lowest value of the zone
+    start: f64,
+    /// Highest value of the zone
+    end: f64,
+    /// Color of filled cells in the zone
+    color: Color,
+    /// Filled symbol override for cells in the zone
+    symbol: Option<String>,
+}
+
+impl Zone {
+    /// Creates a zone covering `start..=end` in slider values
+    ///
+    /// The bounds are swapped if given in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::Zone;
+    ///
+    /// let zone = Zone::new(80.0, 100.0, Color::Red);
+    /// assert!(zone.contains(90.0));
+    /// ```
+    pub fn new(start: f64, end: f64, color: Color) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+            color,
+            symbol: None,
+        }
+    }
+
+    /// Sets the filled symbol used for cells in the zone
+    ///
+    /// The symbol should be as wide as the slider's filled symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::Zone;
+    ///
+    /// let zone = Zone::new(80.0, 100.0, Color::Red).symbol("▓");
+    /// ```
+    pub fn symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Creates low, medium and high zones from the thresholds of a state
+    ///
+    /// The zones match [`SliderState::is_low`], [`SliderState::is_medium`] and
+    /// [`SliderState::is_high`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_slider::zone::Zone;
+    /// use tui_slider::{Slider, SliderState};
+    ///
+    /// let mut state = SliderState::new(85.0, 0.0, 100.0);
+    /// state.set_thresholds(0.6, 0.8);
+    ///
+    /// let slider = Slider::from_state(&state)
+    ///     .zones(Zone::levels(&state, Color::Green, Color::Yellow, Color::Red));
+    /// ```
+    pub fn levels(state: &SliderState, low: Color, medium: Color, high: Color) -> [Zone; 3] {
+        let low_end = state.low_threshold_value();
+        let high_start = state.high_threshold_value();
+        [
+            Zone::new(state.min(), low_end, low),
+            Zone::new(low_end, high_start, medium),
+            Zone::new(high_start, state.max(), high),
+        ]
+    }
+
+    /// Returns true if the value lies in the zone
+    pub fn contains(&self, value: f64) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+
+    /// Returns the lowest value of the zone
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Returns the highest value of the zone
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+
+    /// Returns the color of filled cells in the zone
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the filled symbol override, if any
+    pub(crate) fn filled_symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+}
+
+/// Returns the last zone containing the value
+///
+/// Later zones win where zones share a boundary, so a value sitting exactly on a
+/// threshold belongs to the zone above it.
+pub(crate) fn zone_at(zones: &[Zone], value: f64) -> Option<&Zone> {
+    zones.iter().rev().find(|zone| zone.contains(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_orders_bounds() {
+        let zone = Zone::new(80.0, 20.0, Color::Red);
+        assert_eq!(zone.start(), 20.0);
+        assert_eq!(zone.end(), 80.0);
+        assert!(zone.contains(20.0));
+        assert!(zone.contains(80.0));
+        assert!(!zone.contains(80.1));
+    }
+
+    #[test]
+    fn test_zone_at() {
+        let zones = [
+            Zone::new(0.0, 50.0, Color::Green),
+            Zone::new(50.0, 100.0, Color::Red),
+        ];
+        assert_eq!(zone_at(&zones, 10.0).map(Zone::color), Some(Color::Green));
+        assert_eq!(zone_at(&zones, 50.0).map(Zone::color), Some(Color::Red));
+        assert_eq!(zone_at(&zones, 120.0), None);
+    }
+
+    #[test]
+    fn test_levels_follow_state_thresholds() {
+        let mut state = SliderState::new(50.0, 0.0, 100.0);
+        state.set_thresholds(0.6, 0.8);
+        let zones = Zone::levels(&state, Color::Green, Color::Yellow, Color::Red);
+
+        for value in [0.0, 59.0, 60.0, 79.0, 80.0, 100.0] {
+            state.set_value(value);
+            let expected = if state.is_low() {
+                Color::Green
+            } else if state.is_medium() {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            assert_eq!(zone_at(&zones, value).map(Zone::color), Some(expected));
+        }
+    }
+}